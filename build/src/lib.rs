@@ -0,0 +1,118 @@
+#![allow(clippy::needless_doctest_main)]
+//! Build script helpers for [`embed_it`](https://docs.rs/embed_it).
+//!
+//! The derive reads a directory during macro expansion, but cargo only knows about the files
+//! which are included with `include_bytes!`. So a new, deleted or renamed entry does not trigger
+//! a rebuild. Call [`track_dir`] from a build script of the crate that uses the derive to rebuild
+//! it on any change inside the directory.
+//!
+//! ```no_run
+//! // build.rs
+//! fn main() {
+//!     embed_it_build::track_dir("$CARGO_MANIFEST_DIR/assets").unwrap();
+//! }
+//! ```
+
+use std::{
+    env::VarError,
+    io::{self, Write},
+    path::PathBuf,
+};
+
+/// Tells cargo to rerun the build script, and so to rebuild the crate, if anything changes inside the directory.
+///
+/// The path has the same format as the `path` attribute of the derive: it may contain environment
+/// variables like `$CARGO_MANIFEST_DIR` or `${CARGO_MANIFEST_DIR}`. Relative paths are resolved from the
+/// directory of the package being built.
+///
+/// Note that once a build script emits any `rerun-if-changed` instruction, cargo stops rerunning it on
+/// every change in the package and checks only the listed paths.
+pub fn track_dir(path: &str) -> Result<(), TrackDirError> {
+    track_dir_to(path, &mut io::stdout().lock(), |var| std::env::var(var))
+}
+
+/// The same as [`track_dir`], but writes cargo instructions to `out` and resolves environment variables with `get_env`
+pub fn track_dir_to(
+    path: &str,
+    out: &mut impl Write,
+    get_env: impl Fn(&str) -> Result<String, VarError>,
+) -> Result<(), TrackDirError> {
+    let expanded = expand(path, get_env)?;
+    let dir = std::fs::canonicalize(&expanded)
+        .map_err(|e| TrackDirError::Canonicalize(expanded.clone(), e))?;
+    if !dir.is_dir() {
+        return Err(TrackDirError::NotADir(dir));
+    }
+
+    // cargo scans the entire directory recursively, so any added, removed
+    // or modified entry at any depth changes its fingerprint
+    writeln!(out, "cargo::rerun-if-changed={}", dir.display()).map_err(TrackDirError::Write)
+}
+
+#[derive(Debug, derive_more::Display, derive_more::Error)]
+pub enum TrackDirError {
+    #[display("environment variable '{_0}' error: '{_1}'")]
+    Env(String, #[error(source)] VarError),
+
+    #[display("unable to canonicalize path '{_0}': '{_1}'")]
+    Canonicalize(String, #[error(source)] io::Error),
+
+    #[display("'{}' is not a directory", _0.display())]
+    NotADir(#[error(not(source))] PathBuf),
+
+    #[display("unable to write cargo instructions: {_0}")]
+    Write(io::Error),
+}
+
+fn expand(
+    input: &str,
+    get_env: impl Fn(&str) -> Result<String, VarError>,
+) -> Result<String, TrackDirError> {
+    embed_it_utils::expand::expand(input, get_env).map_err(|e| TrackDirError::Env(e.var, e.error))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env::VarError, fs};
+
+    use super::{TrackDirError, track_dir_to};
+
+    fn get_env(var: &str) -> Result<String, VarError> {
+        match var {
+            "ROOT" => Ok("/root".to_owned()),
+            "DIR" => Ok("dir".to_owned()),
+            _ => Err(VarError::NotPresent),
+        }
+    }
+
+    #[test]
+    fn track_dir() {
+        let dir = std::env::temp_dir().join("embed_it_build_track_dir");
+        fs::create_dir_all(&dir).unwrap();
+        let dir = fs::canonicalize(dir).unwrap();
+
+        let mut out = Vec::new();
+        track_dir_to(dir.to_str().unwrap(), &mut out, get_env).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("cargo::rerun-if-changed={}\n", dir.display())
+        );
+    }
+
+    #[test]
+    fn track_not_a_dir() {
+        let dir = std::env::temp_dir().join("embed_it_build_track_not_a_dir");
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("file.txt");
+        fs::write(&file, b"hello").unwrap();
+
+        let err = track_dir_to(file.to_str().unwrap(), &mut Vec::new(), get_env).unwrap_err();
+        assert!(matches!(err, TrackDirError::NotADir(_)));
+    }
+
+    #[test]
+    fn track_not_existing_dir() {
+        let err = track_dir_to("/this/dir/does/not/exist", &mut Vec::new(), get_env).unwrap_err();
+        assert!(matches!(err, TrackDirError::Canonicalize(..)));
+    }
+}
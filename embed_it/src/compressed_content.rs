@@ -0,0 +1,144 @@
+use std::{io::Read, sync::OnceLock};
+
+/// An algorithm of [`CompressedContent`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    #[cfg(feature = "zstd-decompress")]
+    Zstd,
+
+    #[cfg(feature = "gzip-decompress")]
+    Gzip,
+
+    #[cfg(feature = "brotli-decompress")]
+    Brotli,
+}
+
+/// The compressed content of a file, which is decompressed on the first access.
+///
+/// It is used by the code generated with `compression(store_compressed = "..")`. Only the compressed bytes
+/// are embedded into the binary, and the decompressed content lives in memory after the first access.
+#[derive(Debug)]
+pub struct CompressedContent {
+    compression: Compression,
+    compressed: &'static [u8],
+    content: OnceLock<Vec<u8>>,
+    str_content: OnceLock<&'static str>,
+}
+
+impl CompressedContent {
+    /// Create new instance of [`CompressedContent`]
+    pub const fn new(compression: Compression, compressed: &'static [u8]) -> Self {
+        Self {
+            compression,
+            compressed,
+            content: OnceLock::new(),
+            str_content: OnceLock::new(),
+        }
+    }
+
+    /// The compression algorithm
+    pub const fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    /// The embedded compressed bytes. They can be passed through as is, e.g. with an HTTP `Content-Encoding`
+    pub const fn compressed(&self) -> &'static [u8] {
+        self.compressed
+    }
+
+    /// Returns the decompressed content.
+    ///
+    /// # Panics
+    ///
+    /// If the compressed bytes are corrupted
+    pub fn content(&'static self) -> &'static [u8] {
+        self.content.get_or_init(|| {
+            decompress(self.compression, self.compressed).unwrap_or_else(|e| {
+                panic!(
+                    "unable to decompress the embedded content with {:?}: {e}",
+                    self.compression
+                )
+            })
+        })
+    }
+
+    /// Returns the decompressed content as a str.
+    ///
+    /// # Panics
+    ///
+    /// The same as [`Self::content`] or if the content is not a valid utf8
+    pub fn str_content(&'static self) -> &'static str {
+        self.str_content.get_or_init(|| {
+            core::str::from_utf8(self.content())
+                .unwrap_or_else(|e| panic!("the embedded content is not a valid utf8: {e}"))
+        })
+    }
+}
+
+fn decompress(compression: Compression, compressed: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut content = Vec::new();
+    match compression {
+        #[cfg(feature = "zstd-decompress")]
+        Compression::Zstd => {
+            // the content is produced by the macro, so the window size is trusted
+            ruzstd::decoding::StreamingDecoder::new_with_max_window_size(compressed, 1 << 31)
+                .map_err(std::io::Error::other)?
+                .read_to_end(&mut content)?
+        }
+
+        #[cfg(feature = "gzip-decompress")]
+        Compression::Gzip => flate2::read::GzDecoder::new(compressed).read_to_end(&mut content)?,
+
+        #[cfg(feature = "brotli-decompress")]
+        Compression::Brotli => {
+            brotli_decompressor::Decompressor::new(compressed, 4096).read_to_end(&mut content)?
+        }
+    };
+    Ok(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::{CompressedContent, Compression};
+
+    #[test]
+    #[cfg(feature = "zstd-decompress")]
+    fn zstd() {
+        static VALUE: CompressedContent =
+            CompressedContent::new(Compression::Zstd, &hex!("28b52ffd008829000068656c6c6f"));
+        assert_eq!(VALUE.content(), b"hello");
+        assert_eq!(VALUE.str_content(), "hello");
+        assert_eq!(VALUE.compression(), Compression::Zstd);
+        assert_eq!(VALUE.compressed(), &hex!("28b52ffd008829000068656c6c6f"));
+    }
+
+    #[test]
+    #[cfg(feature = "gzip-decompress")]
+    fn gzip() {
+        static VALUE: CompressedContent = CompressedContent::new(
+            Compression::Gzip,
+            &hex!("1f8b08000000000002ffcb48cdc9c9070086a6103605000000"),
+        );
+        assert_eq!(VALUE.content(), b"hello");
+        assert_eq!(VALUE.str_content(), "hello");
+    }
+
+    #[test]
+    #[cfg(feature = "brotli-decompress")]
+    fn brotli() {
+        static VALUE: CompressedContent =
+            CompressedContent::new(Compression::Brotli, &hex!("0b028068656c6c6f03"));
+        assert_eq!(VALUE.content(), b"hello");
+        assert_eq!(VALUE.str_content(), "hello");
+    }
+
+    #[test]
+    #[cfg(feature = "gzip-decompress")]
+    #[should_panic(expected = "unable to decompress the embedded content")]
+    fn corrupted() {
+        static VALUE: CompressedContent = CompressedContent::new(Compression::Gzip, b"hello");
+        VALUE.content();
+    }
+}
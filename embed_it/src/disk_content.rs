@@ -0,0 +1,159 @@
+use std::{
+    path::Path,
+    sync::{Mutex, PoisonError},
+    time::SystemTime,
+};
+
+/// The content of a file, which is read from the disk at runtime.
+///
+/// It is used by the code generated with the `dev_mode` attribute. The file is read on the first access
+/// and then it is read again only if its modification time has changed.
+///
+/// # Memory
+///
+/// A returned `'static` reference must stay valid forever, so a buffer is never freed. Every change
+/// of the content leaks a new buffer of the file size, and the memory grows without a bound while
+/// the file is being edited. A read of the same content, e.g. after `touch`, reuses the previous buffer.
+/// So it is intended for development only.
+#[derive(Debug)]
+pub struct DiskContent {
+    origin: &'static str,
+    state: Mutex<Option<DiskContentState>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DiskContentState {
+    modified: Option<SystemTime>,
+    content: &'static [u8],
+}
+
+impl DiskContent {
+    /// Create new instance of [`DiskContent`] for an absolute path of a file
+    pub const fn new(origin: &'static str) -> Self {
+        Self {
+            origin,
+            state: Mutex::new(None),
+        }
+    }
+
+    /// The absolute path of the file
+    pub fn origin(&self) -> &'static Path {
+        Path::new(self.origin)
+    }
+
+    /// Returns the actual content of the file.
+    ///
+    /// # Panics
+    ///
+    /// If the file has never been read successfully and it is not readable now
+    pub fn content(&self) -> &'static [u8] {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let modified = std::fs::metadata(self.origin)
+            .and_then(|m| m.modified())
+            .ok();
+
+        if let Some(current) = *state {
+            if modified.is_none() || current.modified == modified {
+                return current.content;
+            }
+        }
+
+        match std::fs::read(self.origin) {
+            Ok(content) => {
+                let content: &'static [u8] = match *state {
+                    Some(current) if current.content == content.as_slice() => current.content,
+                    _ => Box::leak(content.into_boxed_slice()),
+                };
+                *state = Some(DiskContentState { modified, content });
+                content
+            }
+            Err(e) => match *state {
+                Some(current) => current.content,
+                None => panic!("unable to read the file '{}': {e}", self.origin),
+            },
+        }
+    }
+
+    /// Returns the actual content of the file as a str.
+    ///
+    /// # Panics
+    ///
+    /// The same as [`Self::content`] or if the content is not a valid utf8
+    pub fn str_content(&self) -> &'static str {
+        std::str::from_utf8(self.content())
+            .unwrap_or_else(|e| panic!("the file '{}' is not a valid utf8: {e}", self.origin))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        time::{Duration, SystemTime},
+    };
+
+    use super::DiskContent;
+
+    fn temp_file(name: &str, content: &[u8]) -> &'static str {
+        let dir = std::env::temp_dir().join("embed_it_disk_content");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        fs::write(&path, content).unwrap();
+        Box::leak(path.to_str().unwrap().to_owned().into_boxed_str())
+    }
+
+    #[test]
+    fn rereads_modified_file() {
+        let origin = temp_file("rereads_modified_file", b"hello");
+        let content = DiskContent::new(origin);
+        assert_eq!(content.content(), b"hello");
+        assert_eq!(content.str_content(), "hello");
+
+        fs::write(origin, b"world").unwrap();
+        fs::File::options()
+            .write(true)
+            .open(origin)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(10))
+            .unwrap();
+        assert_eq!(content.content(), b"world");
+    }
+
+    #[test]
+    fn reuses_unchanged_content() {
+        let origin = temp_file("reuses_unchanged_content", b"hello");
+        let content = DiskContent::new(origin);
+        let first = content.content();
+
+        fs::File::options()
+            .write(true)
+            .open(origin)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(10))
+            .unwrap();
+        assert!(core::ptr::eq(first, content.content()));
+    }
+
+    #[test]
+    fn returns_cached_if_removed() {
+        let origin = temp_file("returns_cached_if_removed", b"hello");
+        let content = DiskContent::new(origin);
+        assert_eq!(content.content(), b"hello");
+
+        fs::remove_file(origin).unwrap();
+        assert_eq!(content.content(), b"hello");
+    }
+
+    #[test]
+    #[should_panic(expected = "unable to read the file")]
+    fn panics_if_not_exists() {
+        DiskContent::new("/this/file/does/not/exist").content();
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a valid utf8")]
+    fn str_content_panics_if_not_utf8() {
+        let origin = temp_file("str_content_panics_if_not_utf8", &[0xff, 0xfe]);
+        DiskContent::new(origin).str_content();
+    }
+}
@@ -1,15 +1,32 @@
-use std::{fmt::Display, path::Path};
+use core::fmt::Display;
+#[cfg(feature = "std")]
+use std::path::Path;
 
 /// The path of the embedded entry
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct EmbeddedPath {
     relative_path: &'static str,
     name: &'static str,
     stem: &'static str,
+
+    /// It is not a part of the path, so it is ignored by the comparison and the serialization
+    #[cfg_attr(feature = "serde", serde(skip))]
+    root: usize,
+}
+
+impl PartialEq for EmbeddedPath {
+    fn eq(&self, other: &Self) -> bool {
+        self.relative_path == other.relative_path
+            && self.name == other.name
+            && self.stem == other.stem
+    }
 }
 
+impl Eq for EmbeddedPath {}
+
 impl Display for EmbeddedPath {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.relative_path)
     }
 }
@@ -21,11 +38,19 @@ impl EmbeddedPath {
             relative_path,
             name,
             stem,
+            root: 0,
         }
     }
 
+    /// Sets the index of the root, see [`Self::root`]
+    pub const fn with_root(mut self, root: usize) -> Self {
+        self.root = root;
+        self
+    }
+
     /// Relative to the root assets dir path. Is's guaranteed, that valid utf8 inside the path,
     /// because it creates from a &str. Cost-free
+    #[cfg(feature = "std")]
     pub fn relative_path(&self) -> &'static Path {
         Path::new(self.relative_path)
     }
@@ -44,6 +69,12 @@ impl EmbeddedPath {
     pub fn stem(&self) -> &'static str {
         self.stem
     }
+
+    /// The index of the root (in the order of `path` attributes), which the entry is read from.
+    /// For a dir, which is merged from several roots, it is the last one. Cost-free
+    pub const fn root(&self) -> usize {
+        self.root
+    }
 }
 
 #[cfg(test)]
@@ -57,7 +88,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(target_os = "windows")]
+    #[cfg(all(feature = "std", target_os = "windows"))]
     fn relative_path() {
         use std::path::Path;
 
@@ -66,7 +97,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(not(target_os = "windows"))]
+    #[cfg(all(feature = "std", not(target_os = "windows")))]
     fn relative_path() {
         use std::path::Path;
 
@@ -80,5 +111,24 @@ mod tests {
         assert_eq!(path.relative_path_str(), "a/b/c/d.txt");
         assert_eq!(path.name(), "aaa");
         assert_eq!(path.stem(), "bbb");
+        assert_eq!(path.root(), 0);
+        assert_eq!(path.with_root(2).root(), 2);
+    }
+
+    #[test]
+    fn eq_ignores_root() {
+        let path = EmbeddedPath::new("a/b/c/d.txt", "aaa", "bbb");
+        assert_eq!(path, path.with_root(2));
+        assert_ne!(path, EmbeddedPath::new("a/b/c/e.txt", "aaa", "bbb"));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize_without_root() {
+        let path = EmbeddedPath::new("a/b.txt", "b.txt", "b").with_root(2);
+        assert_eq!(
+            serde_json::to_value(path).unwrap(),
+            serde_json::json!({ "relative_path": "a/b.txt", "name": "b.txt", "stem": "b" })
+        );
     }
 }
@@ -0,0 +1,174 @@
+use core::{iter::FusedIterator, slice};
+
+use crate::Entry;
+
+/// Decides whether a relative path of an entry is found
+pub trait PathMatcher {
+    /// Returns `true` if the path matches
+    fn is_match(&self, path: &str) -> bool;
+}
+
+impl<F: Fn(&str) -> bool> PathMatcher for F {
+    fn is_match(&self, path: &str) -> bool {
+        self(path)
+    }
+}
+
+/// Glob patterns are matched the same way as `include(pattern = "..")`: `*` matches `/` as well
+#[cfg(feature = "glob")]
+impl PathMatcher for glob::Pattern {
+    fn is_match(&self, path: &str) -> bool {
+        self.matches(path)
+    }
+}
+
+#[cfg(feature = "regex")]
+impl PathMatcher for regex::Regex {
+    fn is_match(&self, path: &str) -> bool {
+        regex::Regex::is_match(self, path)
+    }
+}
+
+/// An iterator over entries with their relative paths in depth-first order.
+/// It is returned by [`crate::Find::relative_entries`]
+#[derive(Debug, Clone)]
+pub struct RelativeEntries<Dir: 'static, File: 'static> {
+    paths: slice::Iter<'static, &'static str>,
+    entries: slice::Iter<'static, Entry<Dir, File>>,
+    prefix_len: usize,
+}
+
+impl<Dir, File> RelativeEntries<Dir, File> {
+    /// Creates an iterator over entries with their paths, the first `prefix_len` bytes of every path are skipped,
+    /// e.g. a subslice of a generated flattened table of an embed without the path of the dir and a separator
+    pub fn new(
+        paths: &'static [&'static str],
+        entries: &'static [Entry<Dir, File>],
+        prefix_len: usize,
+    ) -> Self {
+        assert_eq!(paths.len(), entries.len());
+        Self {
+            paths: paths.iter(),
+            entries: entries.iter(),
+            prefix_len,
+        }
+    }
+}
+
+impl<Dir, File> Iterator for RelativeEntries<Dir, File> {
+    type Item = (&'static str, &'static Entry<Dir, File>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let path = self.paths.next()?;
+        let entry = self.entries.next()?;
+        Some((&path[self.prefix_len..], entry))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl<Dir, File> ExactSizeIterator for RelativeEntries<Dir, File> {}
+
+impl<Dir, File> FusedIterator for RelativeEntries<Dir, File> {}
+
+/// An iterator over entries, whose relative paths match `M`, in depth-first order.
+/// It is returned by [`crate::Find`] methods
+#[derive(Debug, Clone)]
+pub struct Found<Dir: 'static, File: 'static, M> {
+    entries: RelativeEntries<Dir, File>,
+    matcher: M,
+}
+
+impl<Dir, File, M: PathMatcher> Found<Dir, File, M> {
+    /// Create new instance of [`Found`] over entries with their relative paths,
+    /// e.g. [`crate::Find::relative_entries`]
+    pub fn new(entries: RelativeEntries<Dir, File>, matcher: M) -> Self {
+        Self { entries, matcher }
+    }
+}
+
+impl<Dir, File, M: PathMatcher> Iterator for Found<Dir, File, M> {
+    type Item = &'static Entry<Dir, File>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let matcher = &self.matcher;
+        self.entries
+            .find(|(path, _)| matcher.is_match(path))
+            .map(|(_, entry)| entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.entries.size_hint().1)
+    }
+}
+
+impl<Dir, File, M: PathMatcher> FusedIterator for Found<Dir, File, M> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::Entry;
+
+    use super::{Found, RelativeEntries};
+
+    const PATHS: &[&str] = &[
+        "templates",
+        "templates/index.html",
+        "templates/mail",
+        "templates/mail/welcome.html",
+        "templates/mail/welcome.txt",
+        "migrations/0001.sql",
+    ];
+    const ENTRIES: &[Entry<u8, u16>] = &[
+        Entry::Dir(0),
+        Entry::File(1),
+        Entry::Dir(2),
+        Entry::File(3),
+        Entry::File(4),
+        Entry::File(5),
+    ];
+
+    fn all() -> RelativeEntries<u8, u16> {
+        RelativeEntries::new(PATHS, ENTRIES, 0)
+    }
+
+    #[test]
+    fn relative_entries() {
+        let mail = RelativeEntries::new(&PATHS[3..5], &ENTRIES[3..5], "templates/mail/".len());
+        assert_eq!(
+            mail.collect::<Vec<_>>(),
+            vec![
+                ("welcome.html", &Entry::File(3)),
+                ("welcome.txt", &Entry::File(4))
+            ]
+        );
+        assert_eq!(all().len(), 6);
+    }
+
+    #[test]
+    fn closure() {
+        let found = Found::new(all(), |p: &str| p.ends_with(".sql")).collect::<Vec<_>>();
+        assert_eq!(found, vec![&Entry::File(5)]);
+    }
+
+    #[test]
+    #[cfg(feature = "glob")]
+    fn glob() {
+        let pattern = glob::Pattern::new("templates/**/*.html").unwrap();
+        let found = Found::new(all(), pattern).collect::<Vec<_>>();
+        assert_eq!(found, vec![&Entry::File(1), &Entry::File(3)]);
+
+        let pattern = glob::Pattern::new("*.txt").unwrap();
+        let found = Found::new(all(), pattern).collect::<Vec<_>>();
+        assert_eq!(found, vec![&Entry::File(4)]);
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn regex() {
+        let regex = regex::Regex::new(r"^templates/mail/.+\.(html|txt)$").unwrap();
+        let found = Found::new(all(), regex).collect::<Vec<_>>();
+        assert_eq!(found, vec![&Entry::File(3), &Entry::File(4)]);
+    }
+}
@@ -1,16 +1,43 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![allow(clippy::needless_doctest_main)]
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
+#[cfg(feature = "any-decompression")]
+mod compressed_content;
+#[cfg(feature = "std")]
+mod disk_content;
 mod embedded_path;
+mod find;
+mod lookup;
+mod manifest;
 mod metadata;
+mod resolver;
+mod sorted_index;
 mod traits;
+mod unknown_entry;
+mod walk;
 
+#[cfg(feature = "any-decompression")]
+pub use compressed_content::{CompressedContent, Compression};
+#[cfg(feature = "std")]
+pub use disk_content::DiskContent;
 pub use embed_it_macros::Embed;
 pub use embedded_path::EmbeddedPath;
+pub use find::{Found, PathMatcher, RelativeEntries};
+pub use lookup::LookupOptions;
+#[doc(hidden)]
+pub use lookup::str_eq;
+pub use manifest::{ManifestDir, ManifestEntry, ManifestFile, ManifestHash};
 pub use metadata::Metadata;
+pub use resolver::{Redirect, Resolved, Resolver, TrailingSlash};
+pub use sorted_index::SortedIndex;
 pub use traits::{
-    ChildOf, Content, DirectChildCount, Entries, EntryPath, Index, Meta, RecursiveChildCount,
-    StrContent,
+    ChildOf, Content, DirectChildCount, Entries, EntryPath, Find, HashedName, Index, LinkTarget,
+    Manifest, Meta, Mime, RecursiveChildCount, StrContent, Walk,
 };
+pub use unknown_entry::UnknownEntry;
+pub use walk::BreadthFirst;
+#[doc(hidden)]
+pub use walk::{concat, concat_depths};
 
 pub use embed_it_utils::entry::Entry;
 
@@ -21,7 +48,7 @@ pub use traits::hashes::Md5Hash;
 pub use traits::hashes::Sha1Hash;
 
 #[cfg(feature = "sha2")]
-pub use traits::hashes::{Sha2_224Hash, Sha2_256Hash, Sha2_384Hash, Sha2_512Hash};
+pub use traits::hashes::{Integrity, Sha2_224Hash, Sha2_256Hash, Sha2_384Hash, Sha2_512Hash};
 
 #[cfg(feature = "sha3")]
 pub use traits::hashes::{Sha3_224Hash, Sha3_256Hash, Sha3_384Hash, Sha3_512Hash};
@@ -30,10 +57,10 @@ pub use traits::hashes::{Sha3_224Hash, Sha3_256Hash, Sha3_384Hash, Sha3_512Hash}
 pub use traits::hashes::Blake3_256Hash;
 
 #[cfg(feature = "gzip")]
-pub use traits::compression::GzipContent;
+pub use traits::compression::{GzipContent, MaybeGzipContent};
 
 #[cfg(feature = "brotli")]
-pub use traits::compression::BrotliContent;
+pub use traits::compression::{BrotliContent, MaybeBrotliContent};
 
 #[cfg(feature = "zstd")]
-pub use traits::compression::ZstdContent;
+pub use traits::compression::{MaybeZstdContent, ZstdContent};
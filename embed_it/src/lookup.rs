@@ -0,0 +1,353 @@
+/// How a path passed to `Index::get` is normalized before the lookup.
+///
+/// It is used by the code generated with `index(..)` options. Keys of the index are normalized
+/// at compile time, so only the requested path is processed, and it never allocates for the sorted index
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LookupOptions {
+    /// Every `\` is treated as `/`
+    pub alt_separator: bool,
+
+    /// ASCII letters are compared case-insensitively
+    pub case_insensitive: bool,
+
+    /// Leading `/`, duplicate separators and `.` segments are skipped, and a path with `..` is not found
+    pub normalize: bool,
+
+    /// `%XX` sequences are decoded. An encoded separator (`%2F`, or `%5C` with `alt_separator`)
+    /// is never a separator, so `..` can't be hidden with it, and such a path is not found
+    pub percent_decode: bool,
+}
+
+impl LookupOptions {
+    /// Options, which look up a path as is
+    pub const EXACT: Self = Self {
+        alt_separator: false,
+        case_insensitive: false,
+        normalize: false,
+        percent_decode: false,
+    };
+
+    /// Returns the normalized path or `None` if the path can't be found: it contains `..`
+    /// or the decoded path is not a valid utf8
+    #[cfg(feature = "std")]
+    pub fn normalize(&self, path: &str) -> Option<String> {
+        let mut cursor = Cursor::new(path.as_bytes(), *self);
+        let mut result = Vec::with_capacity(path.len());
+        loop {
+            match cursor.next() {
+                Next::Byte(b) => result.push(b),
+                Next::End => break,
+                Next::Rejected => return None,
+            }
+        }
+        String::from_utf8(result).ok()
+    }
+
+    /// Returns the rest of the path inside the mount point or `None` if the path is outside it.
+    /// The mount point is a key of the index, so it is compared with the normalized path,
+    /// but the rest is returned as is to be looked up by the mounted embed
+    pub fn strip_mount<'a>(&self, path: &'a str, mount: &str) -> Option<&'a str> {
+        let mut cursor = Cursor::new(path.as_bytes(), *self);
+        for &expected in mount.as_bytes() {
+            match cursor.next() {
+                Next::Byte(b) if b == expected => {}
+                _ => return None,
+            }
+        }
+        match cursor.next() {
+            Next::Byte(b'/') => path.get(cursor.pos..),
+            _ => None,
+        }
+    }
+}
+
+/// Compares strings at compile time. It is used by the generated code to check `mounted_at` of a mounted embed
+#[doc(hidden)]
+pub const fn str_eq(left: &str, right: &str) -> bool {
+    let (left, right) = (left.as_bytes(), right.as_bytes());
+    if left.len() != right.len() {
+        return false;
+    }
+    let mut idx = 0;
+    while idx < left.len() {
+        if left[idx] != right[idx] {
+            return false;
+        }
+        idx += 1;
+    }
+    true
+}
+
+/// The next byte of a normalized path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Next {
+    Byte(u8),
+    End,
+    Rejected,
+}
+
+/// Yields bytes of a normalized path one by one, so it works in `const` contexts without allocations
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Cursor<'a> {
+    path: &'a [u8],
+    options: LookupOptions,
+    pos: usize,
+
+    /// The end of the current segment, if `normalize` is enabled and a segment is being emitted
+    segment_end: Option<usize>,
+
+    /// A separator must be emitted before the next segment
+    separator_pending: bool,
+}
+
+impl<'a> Cursor<'a> {
+    pub(crate) const fn new(path: &'a [u8], options: LookupOptions) -> Self {
+        Self {
+            path,
+            options,
+            pos: 0,
+            segment_end: None,
+            separator_pending: false,
+        }
+    }
+
+    pub(crate) const fn next(&mut self) -> Next {
+        if !self.options.normalize {
+            if self.pos >= self.path.len() {
+                return Next::End;
+            }
+            let (b, len) = self.decode_at(self.pos);
+            if len > 1 && self.is_separator(b) {
+                return Next::Rejected;
+            }
+            self.pos += len;
+            return Next::Byte(self.fold(b));
+        }
+
+        loop {
+            if let Some(end) = self.segment_end {
+                if self.pos < end {
+                    let (b, len) = self.decode_at(self.pos);
+                    if len > 1 && self.is_separator(b) {
+                        return Next::Rejected;
+                    }
+                    self.pos += len;
+                    return Next::Byte(self.fold(b));
+                }
+                self.segment_end = None;
+                self.separator_pending = true;
+            }
+
+            while self.pos < self.path.len() && self.is_separator(self.path[self.pos]) {
+                self.pos += 1;
+            }
+            if self.pos >= self.path.len() {
+                return Next::End;
+            }
+
+            let start = self.pos;
+            let mut end = start;
+            while end < self.path.len() && !self.is_separator(self.path[end]) {
+                end += 1;
+            }
+
+            match self.dots(start, end) {
+                1 => {
+                    self.pos = end;
+                    continue;
+                }
+                2 => return Next::Rejected,
+                _ => {}
+            }
+
+            self.segment_end = Some(end);
+            if self.separator_pending {
+                self.separator_pending = false;
+                return Next::Byte(b'/');
+            }
+        }
+    }
+
+    const fn is_separator(&self, b: u8) -> bool {
+        b == b'/' || (self.options.alt_separator && b == b'\\')
+    }
+
+    const fn fold(&self, b: u8) -> u8 {
+        if self.options.alt_separator && b == b'\\' {
+            b'/'
+        } else if self.options.case_insensitive {
+            b.to_ascii_lowercase()
+        } else {
+            b
+        }
+    }
+
+    /// Returns a decoded byte at the position and the number of consumed bytes
+    const fn decode_at(&self, pos: usize) -> (u8, usize) {
+        let b = self.path[pos];
+        if self.options.percent_decode && b == b'%' {
+            if let (Some(high), Some(low)) = (hex(self.path, pos + 1), hex(self.path, pos + 2)) {
+                return ((high << 4) | low, 3);
+            }
+        }
+        (b, 1)
+    }
+
+    /// Returns the number of dots if the segment consists of one or two dots only, otherwise 0
+    const fn dots(&self, start: usize, end: usize) -> usize {
+        let mut pos = start;
+        let mut dots = 0;
+        while pos < end {
+            let (b, len) = self.decode_at(pos);
+            if b != b'.' || dots == 2 {
+                return 0;
+            }
+            dots += 1;
+            pos += len;
+        }
+        dots
+    }
+}
+
+const fn hex(path: &[u8], pos: usize) -> Option<u8> {
+    if pos >= path.len() {
+        return None;
+    }
+    match path[pos] {
+        b @ b'0'..=b'9' => Some(b - b'0'),
+        b @ b'a'..=b'f' => Some(b - b'a' + 10),
+        b @ b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cursor, LookupOptions, Next, str_eq};
+
+    fn normalized(path: &str, options: LookupOptions) -> Option<Vec<u8>> {
+        let mut cursor = Cursor::new(path.as_bytes(), options);
+        let mut result = Vec::new();
+        loop {
+            match cursor.next() {
+                Next::Byte(b) => result.push(b),
+                Next::End => return Some(result),
+                Next::Rejected => return None,
+            }
+        }
+    }
+
+    fn check(path: &str, options: LookupOptions, expected: Option<&str>) {
+        assert_eq!(
+            normalized(path, options),
+            expected.map(|e| e.as_bytes().to_vec()),
+            "{path}"
+        );
+    }
+
+    const ALL: LookupOptions = LookupOptions {
+        alt_separator: true,
+        case_insensitive: true,
+        normalize: true,
+        percent_decode: true,
+    };
+
+    #[test]
+    fn exact() {
+        check(
+            "/Static/./a%20b",
+            LookupOptions::EXACT,
+            Some("/Static/./a%20b"),
+        );
+        check("", LookupOptions::EXACT, Some(""));
+    }
+
+    #[test]
+    fn normalize() {
+        let options = LookupOptions {
+            normalize: true,
+            ..LookupOptions::EXACT
+        };
+        check(
+            "/Static/./css//main.css",
+            options,
+            Some("Static/css/main.css"),
+        );
+        check("./a/b/", options, Some("a/b"));
+        check("///", options, Some(""));
+        check("", options, Some(""));
+        check("a/.../b", options, Some("a/.../b"));
+        check("a/.b/b.", options, Some("a/.b/b."));
+        check("a/../b", options, None);
+        check("..", options, None);
+        check("a\\b", options, Some("a\\b"));
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let options = LookupOptions {
+            case_insensitive: true,
+            ..LookupOptions::EXACT
+        };
+        check("A/B.TXT", options, Some("a/b.txt"));
+        check("Привет", options, Some("Привет"));
+    }
+
+    #[test]
+    fn percent_decode() {
+        let options = LookupOptions {
+            percent_decode: true,
+            ..LookupOptions::EXACT
+        };
+        check("a%20b%5Cc", options, Some("a b\\c"));
+        check("a%2Fb", options, None);
+        check("100%", options, Some("100%"));
+        check("%zz%4", options, Some("%zz%4"));
+    }
+
+    #[test]
+    fn all() {
+        check(
+            "\\Static\\.\\css\\\\Main%2ECSS",
+            ALL,
+            Some("static/css/main.css"),
+        );
+        check("/static/%2e%2E/secret", ALL, None);
+        check("a%2F..%2Fb", ALL, None);
+        check("a%5C..%5Cb", ALL, None);
+        check("a%2f%2e%2e%2fb", ALL, None);
+        check("/static/%2e/a%20b.txt", ALL, Some("static/a b.txt"));
+    }
+
+    #[test]
+    fn strip_mount() {
+        let exact = LookupOptions::EXACT;
+        assert_eq!(exact.strip_mount("sub/a/b.txt", "sub"), Some("a/b.txt"));
+        assert_eq!(exact.strip_mount("a/sub/b.txt", "a/sub"), Some("b.txt"));
+        assert_eq!(exact.strip_mount("sub", "sub"), None);
+        assert_eq!(exact.strip_mount("subway/a", "sub"), None);
+        assert_eq!(exact.strip_mount("Sub/a", "sub"), None);
+        assert_eq!(exact.strip_mount("sub\\a", "sub"), None);
+
+        assert_eq!(ALL.strip_mount("/Sub//A%20B", "sub"), Some("A%20B"));
+        assert_eq!(ALL.strip_mount("./SUB\\a", "sub"), Some("a"));
+        assert_eq!(ALL.strip_mount("sub/", "sub"), None);
+        assert_eq!(ALL.strip_mount("../sub/a", "sub"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn to_string() {
+        assert_eq!(ALL.normalize("/A//B").as_deref(), Some("a/b"));
+        assert_eq!(ALL.normalize("a/../b"), None);
+        assert_eq!(ALL.normalize("%ff"), None);
+    }
+
+    #[test]
+    fn const_str_eq() {
+        const _: () = assert!(str_eq("a/sub", "a/sub"));
+        assert!(!str_eq("a/sub", "a/suB"));
+        assert!(!str_eq("a/sub", "a/sub/"));
+        assert!(str_eq("", ""));
+    }
+}
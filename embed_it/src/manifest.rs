@@ -0,0 +1,53 @@
+use crate::{EmbeddedPath, Entry, Metadata};
+
+/// An entry of the manifest, see [`crate::Manifest`]
+pub type ManifestEntry = Entry<&'static ManifestDir, &'static ManifestFile>;
+
+/// A dir of the manifest with its children
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ManifestDir {
+    pub path: EmbeddedPath,
+    pub metadata: Metadata,
+
+    /// Hashes of the derived hash traits
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_hashes"))]
+    pub hashes: &'static [ManifestHash],
+
+    /// Direct children in the same order as [`crate::Entries`]
+    pub entries: &'static [ManifestEntry],
+}
+
+/// A file of the manifest
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ManifestFile {
+    pub path: EmbeddedPath,
+    pub metadata: Metadata,
+
+    /// The size of the original content in bytes
+    pub size: u64,
+
+    /// Hashes of the derived hash traits
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_hashes"))]
+    pub hashes: &'static [ManifestHash],
+}
+
+/// A hash of an entry in the manifest
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ManifestHash {
+    /// The method of the hash trait, e.g. `sha2_256`
+    pub name: &'static str,
+
+    /// The lowercase hex of the hash
+    pub hex: &'static str,
+}
+
+/// Hashes are serialized as a map from the name to the hex
+#[cfg(feature = "serde")]
+fn serialize_hashes<S: serde::Serializer>(
+    hashes: &&'static [ManifestHash],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(hashes.iter().map(|h| (h.name, h.hex)))
+}
@@ -1,7 +1,10 @@
-use std::time::{Duration, SystemTime};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::SystemTime;
 
 /// Metadata for a fs entry.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Metadata {
     /// Unix timestamp when the entry was last accessed.
     pub accessed: Option<Duration>,
@@ -30,6 +33,7 @@ impl Metadata {
     /// Get the time the entry was last accessed.
     ///
     /// Uses [`std::fs::Metadata::accessed()`].
+    #[cfg(feature = "std")]
     pub fn accessed(&self) -> Option<SystemTime> {
         self.accessed.map(|d| SystemTime::UNIX_EPOCH + d)
     }
@@ -37,6 +41,7 @@ impl Metadata {
     /// Get the time the entry was created.
     ///
     /// Uses [`std::fs::Metadata::accessed()`].
+    #[cfg(feature = "std")]
     pub fn created(&self) -> Option<SystemTime> {
         self.created.map(|d| SystemTime::UNIX_EPOCH + d)
     }
@@ -44,12 +49,13 @@ impl Metadata {
     /// Get the time the entry was last modified.
     ///
     /// Uses [`std::fs::Metadata::accessed()`].
+    #[cfg(feature = "std")]
     pub fn modified(&self) -> Option<SystemTime> {
         self.modified.map(|d| SystemTime::UNIX_EPOCH + d)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use crate::Metadata;
     use std::time::{Duration, SystemTime};
@@ -0,0 +1,145 @@
+use core::ops::Deref;
+
+use crate::{Entry, Index};
+
+/// What to do with a trailing slash, which does not match the kind of an entry
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TrailingSlash {
+    /// `docs` and `docs/` are the same, as well as `a.txt` and `a.txt/`
+    #[default]
+    Ignore,
+
+    /// `docs` is redirected to `docs/` if it is a dir, and `a.txt/` to `a.txt` if it is a file
+    Redirect,
+}
+
+/// A redirect of [`Resolved::Redirect`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Redirect {
+    /// The path is a dir, so a slash must be appended
+    AddSlash,
+
+    /// The path is a file, so trailing slashes must be removed
+    RemoveSlash,
+}
+
+/// The result of [`Resolver::resolve`]
+#[derive(Debug)]
+pub enum Resolved<File: 'static> {
+    /// The file with the path or an index file of the dir with the path
+    File(&'static File),
+
+    /// Nothing is found, so it is the fallback file
+    Fallback(&'static File),
+
+    /// The path must be changed according to [`TrailingSlash::Redirect`]
+    Redirect(Redirect),
+
+    /// Nothing is found and there is no fallback file
+    NotFound,
+}
+
+impl<File> Clone for Resolved<File> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<File> Copy for Resolved<File> {}
+
+/// Resolves request-like paths to files on top of [`Index::get`], e.g. for web serving:
+/// a dir is resolved to its index file, and an unknown path to the fallback file of a single-page app.
+///
+/// ```rust
+/// use embed_it::{Resolved, Resolver, TrailingSlash};
+///
+/// #[derive(embed_it::Embed)]
+/// #[embed(path = "$CARGO_MANIFEST_DIR/../example_dirs/assets")]
+/// pub struct Assets;
+///
+/// fn main() {
+///     let resolver = Resolver {
+///         index_files: &["hello"],
+///         fallback: Some("hello.txt"),
+///         trailing_slash: TrailingSlash::Ignore,
+///     };
+///
+///     let Resolved::File(file) = resolver.resolve(&Assets, "/one_txt/") else {
+///         unreachable!()
+///     };
+///     assert_eq!(file.path().relative_path_str(), "one_txt/hello");
+///
+///     let Resolved::Fallback(file) = resolver.resolve(&Assets, "/some/route") else {
+///         unreachable!()
+///     };
+///     assert_eq!(file.path().relative_path_str(), "hello.txt");
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Resolver<'a> {
+    /// Names of files, which are looked up in a dir in the order. Default: `["index.html"]`
+    pub index_files: &'a [&'a str],
+
+    /// The path of a file, which is resolved if nothing is found. Default: `None`
+    pub fallback: Option<&'a str>,
+
+    /// What to do with a trailing slash. Default: [`TrailingSlash::Ignore`]
+    pub trailing_slash: TrailingSlash,
+}
+
+impl Default for Resolver<'_> {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl<'a> Resolver<'a> {
+    /// The default resolver: `index.html` of dirs, no fallback and trailing slashes are ignored
+    pub const DEFAULT: Self = Self {
+        index_files: &["index.html"],
+        fallback: None,
+        trailing_slash: TrailingSlash::Ignore,
+    };
+
+    /// Resolves the path relative to the dir. Leading slashes are ignored
+    pub fn resolve<D, F, T>(&self, dir: &T, path: &str) -> Resolved<F>
+    where
+        T: Index<D, F> + ?Sized,
+        D: Deref + 'static,
+        D::Target: Index<D, F>,
+        F: 'static,
+    {
+        let path = path.trim_start_matches('/');
+        let trimmed = path.trim_end_matches('/');
+        let has_slash = trimmed.len() != path.len();
+        let redirect = self.trailing_slash == TrailingSlash::Redirect;
+
+        match dir.get(trimmed) {
+            Some(Entry::File(_)) if has_slash && redirect => {
+                Resolved::Redirect(Redirect::RemoveSlash)
+            }
+            Some(Entry::File(file)) => Resolved::File(file),
+            Some(Entry::Dir(_)) if !has_slash && redirect && !trimmed.is_empty() => {
+                Resolved::Redirect(Redirect::AddSlash)
+            }
+            Some(Entry::Dir(d)) => self
+                .index_files
+                .iter()
+                .find_map(|name| d.get(name).and_then(|e| e.as_ref().file()))
+                .map_or_else(|| self.fallback(dir), Resolved::File),
+            None => self.fallback(dir),
+        }
+    }
+
+    fn fallback<D, F, T>(&self, dir: &T) -> Resolved<F>
+    where
+        T: Index<D, F> + ?Sized,
+        D: 'static,
+        F: 'static,
+    {
+        self.fallback
+            .and_then(|path| dir.get(path.trim_start_matches('/')))
+            .and_then(|e| e.as_ref().file())
+            .map_or(Resolved::NotFound, Resolved::Fallback)
+    }
+}
@@ -0,0 +1,206 @@
+use core::cmp::Ordering;
+
+use crate::lookup::{Cursor, LookupOptions, Next};
+
+/// A static index of values sorted by a relative path.
+///
+/// It is used by the `Index` trait generated with `index(kind = "sorted")`. A lookup is a binary search
+/// over a static slice, so it never allocates or locks and can be used in `const` contexts.
+#[derive(Debug, Clone, Copy)]
+pub struct SortedIndex<T: 'static> {
+    entries: &'static [(&'static str, T)],
+}
+
+impl<T> SortedIndex<T> {
+    /// Create new instance of [`SortedIndex`].
+    ///
+    /// # Panics
+    ///
+    /// If the entries are not sorted by a path or contain the same path twice.
+    /// In a `const` context it fails the compilation
+    pub const fn new(entries: &'static [(&'static str, T)]) -> Self {
+        let mut i = 1;
+        while i < entries.len() {
+            if !matches!(
+                compare(
+                    entries[i - 1].0.as_bytes(),
+                    Cursor::new(entries[i].0.as_bytes(), LookupOptions::EXACT)
+                ),
+                Ordering::Less
+            ) {
+                panic!("entries must be sorted by a path without duplicates");
+            }
+            i += 1;
+        }
+        Self { entries }
+    }
+
+    /// Finds a value by the path
+    pub const fn get(&self, path: &str) -> Option<&'static T> {
+        self.get_with(path, LookupOptions::EXACT)
+    }
+
+    /// Finds a value by the path, where every `\` is treated as `/`
+    pub const fn get_with_alt_separator(&self, path: &str) -> Option<&'static T> {
+        self.get_with(
+            path,
+            LookupOptions {
+                alt_separator: true,
+                ..LookupOptions::EXACT
+            },
+        )
+    }
+
+    /// Finds a value by the path normalized with the options.
+    /// The entries must be normalized the same way
+    pub const fn get_with(&self, path: &str, options: LookupOptions) -> Option<&'static T> {
+        let cursor = Cursor::new(path.as_bytes(), options);
+
+        // a rejected path is not compared with entries
+        let mut check = cursor;
+        loop {
+            match check.next() {
+                Next::Byte(_) => {}
+                Next::End => break,
+                Next::Rejected => return None,
+            }
+        }
+
+        let entries = self.entries;
+        let mut low = 0;
+        let mut high = entries.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match compare(entries[mid].0.as_bytes(), cursor) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Some(&entries[mid].1),
+            }
+        }
+        None
+    }
+
+    /// All entries sorted by a path
+    pub const fn entries(&self) -> &'static [(&'static str, T)] {
+        self.entries
+    }
+
+    /// The number of entries
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no entries
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+const fn compare(key: &[u8], mut path: Cursor<'_>) -> Ordering {
+    let mut i = 0;
+    loop {
+        let right = match path.next() {
+            Next::Byte(b) => b,
+            Next::End | Next::Rejected => {
+                return if i < key.len() {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                };
+            }
+        };
+        if i >= key.len() {
+            return Ordering::Less;
+        }
+
+        let left = key[i];
+        if left < right {
+            return Ordering::Less;
+        }
+        if left > right {
+            return Ordering::Greater;
+        }
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LookupOptions;
+
+    use super::SortedIndex;
+
+    const INDEX: SortedIndex<u8> = SortedIndex::new(&[
+        ("", 0),
+        ("a", 1),
+        ("a/b", 2),
+        ("a/b/c.txt", 3),
+        ("ab", 4),
+        ("b.txt", 5),
+    ]);
+
+    #[test]
+    fn get() {
+        assert_eq!(INDEX.get(""), Some(&0));
+        assert_eq!(INDEX.get("a"), Some(&1));
+        assert_eq!(INDEX.get("a/b"), Some(&2));
+        assert_eq!(INDEX.get("a/b/c.txt"), Some(&3));
+        assert_eq!(INDEX.get("ab"), Some(&4));
+        assert_eq!(INDEX.get("b.txt"), Some(&5));
+
+        assert_eq!(INDEX.get("a/"), None);
+        assert_eq!(INDEX.get("a\\b"), None);
+        assert_eq!(INDEX.get("c"), None);
+        assert_eq!(SortedIndex::<u8>::new(&[]).get(""), None);
+    }
+
+    #[test]
+    fn get_with_alt_separator() {
+        assert_eq!(INDEX.get_with_alt_separator("a\\b\\c.txt"), Some(&3));
+        assert_eq!(INDEX.get_with_alt_separator("a/b\\c.txt"), Some(&3));
+        assert_eq!(INDEX.get_with_alt_separator("a/b/c.txt"), Some(&3));
+        assert_eq!(INDEX.get_with_alt_separator("a\\"), None);
+    }
+
+    #[test]
+    fn get_with() {
+        const OPTIONS: LookupOptions = LookupOptions {
+            alt_separator: true,
+            case_insensitive: true,
+            normalize: true,
+            percent_decode: true,
+        };
+        assert_eq!(INDEX.get_with("/A/./B//C.TXT", OPTIONS), Some(&3));
+        assert_eq!(INDEX.get_with(r"\\a\b", OPTIONS), Some(&2));
+        assert_eq!(INDEX.get_with("%41b", OPTIONS), Some(&4));
+        assert_eq!(INDEX.get_with("/", OPTIONS), Some(&0));
+        assert_eq!(INDEX.get_with("a/../b.txt", OPTIONS), None);
+        assert_eq!(INDEX.get_with("/a/b/", LookupOptions::EXACT), None);
+
+        const VALUE: Option<&u8> = INDEX.get_with("./B.txt", OPTIONS);
+        assert_eq!(VALUE, Some(&5));
+    }
+
+    #[test]
+    fn const_get() {
+        const VALUE: Option<&u8> = INDEX.get("a/b");
+        assert_eq!(VALUE, Some(&2));
+        assert_eq!(INDEX.len(), 6);
+        assert!(!INDEX.is_empty());
+        assert_eq!(INDEX.entries()[5], ("b.txt", 5));
+    }
+
+    #[test]
+    #[should_panic(expected = "entries must be sorted by a path without duplicates")]
+    fn new_unsorted() {
+        static ENTRIES: [(&str, u8); 2] = [("b", 0), ("a", 1)];
+        SortedIndex::new(&ENTRIES);
+    }
+
+    #[test]
+    #[should_panic(expected = "entries must be sorted by a path without duplicates")]
+    fn new_duplicate() {
+        static ENTRIES: [(&str, u8); 2] = [("a", 0), ("a", 1)];
+        SortedIndex::new(&ENTRIES);
+    }
+}
@@ -24,12 +24,48 @@ pub trait StrContent {
     fn str_content(&self) -> &'static str;
 }
 
+/// Provides the mime type of a file
+pub trait Mime {
+    /// Get the mime type of the file, like `text/html`.
+    ///
+    /// If implementation is generated by the macro, it is determined at compile time by an explicit mapping,
+    /// the extension or the content of the file. Unknown files are `application/octet-stream`
+    fn mime(&self) -> &'static str;
+}
+
+/// Provides the target of a symlink, which is embedded with `symlinks = "preserve"`
+pub trait LinkTarget {
+    /// Get the target path of the symlink as it is written in the link, or `None` if the file is not a symlink.
+    ///
+    /// The content of a preserved symlink is its target path as well
+    fn link_target(&self) -> Option<&'static str>;
+}
+
+/// Provides the content-addressed path of a file
+pub trait HashedName {
+    /// Get the relative path of the file with a hash of its content inserted before the extension,
+    /// like `js/app.3fa2c1d0.js`. It changes whenever the content changes, so it can be cached forever.
+    ///
+    /// If implementation is generated by the macro, it is computed at compile time, and the `Index`
+    /// of the root resolves this path to the same file
+    fn hashed_path(&self) -> &'static str;
+}
+
 /// Provides metadata of an entry   
 pub trait Meta {
     /// Get the metadata of the entry
     fn metadata(&self) -> &'static Metadata;
 }
 
+/// Provides the manifest of an entry: its path, metadata, size and hashes, and the same for all children recursively.
+/// With the `serde` feature it can be serialized, e.g. to diff asset sets between releases
+pub trait Manifest {
+    /// Get the manifest of the entry
+    ///
+    /// If implementation is generated by the macro, this value is `const` and computed at compile time
+    fn manifest(&self) -> crate::ManifestEntry;
+}
+
 /// A trait for accessing the number of direct children in a dir-like structure.
 ///
 /// This trait provides a method to retrieve a constant value representing the number of
@@ -61,6 +97,39 @@ pub trait Entries<Dir, File> {
     fn entries(&self) -> &'static [crate::Entry<Dir, File>];
 }
 
+/// Provides access to the all children recursively as a flat list
+pub trait Walk<Dir: 'static, File: 'static> {
+    /// All children recursively in depth-first order: every directory is followed by its children.
+    /// Children of a directory are ordered the same way as in [`Entries`]
+    fn walk(&self) -> &'static [crate::Entry<Dir, File>];
+
+    /// All children recursively in breadth-first order: direct children first,
+    /// then children of the first level directories and so on
+    fn walk_breadth_first(&self) -> crate::BreadthFirst<Dir, File>;
+}
+
+/// Provides queries over the all children recursively by their paths
+pub trait Find<Dir: 'static, File: 'static> {
+    /// All children recursively with paths relative to the current directory, in depth-first order
+    fn relative_entries(&self) -> crate::RelativeEntries<Dir, File>;
+
+    /// Finds all children recursively, whose relative paths match the glob pattern, e.g. `templates/**/*.html`.
+    /// Like in `include(pattern = "..")`, `*` matches `/` as well
+    #[cfg(feature = "glob")]
+    fn glob(
+        &self,
+        pattern: &str,
+    ) -> Result<crate::Found<Dir, File, glob::Pattern>, glob::PatternError> {
+        glob::Pattern::new(pattern).map(|p| crate::Found::new(self.relative_entries(), p))
+    }
+
+    /// Finds all children recursively, whose relative paths match the regex
+    #[cfg(feature = "regex")]
+    fn regex(&self, regex: &str) -> Result<crate::Found<Dir, File, regex::Regex>, regex::Error> {
+        regex::Regex::new(regex).map(|r| crate::Found::new(self.relative_entries(), r))
+    }
+}
+
 /// Provides access to the all children recursively
 pub trait Index<Dir, File> {
     /// Resolve a child by the path recursively
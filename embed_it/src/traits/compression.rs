@@ -27,6 +27,15 @@ pub trait GzipContent {
     fn gzip_content(&self) -> &'static [u8];
 }
 
+#[cfg(feature = "gzip")]
+/// The same as [`GzipContent`], but it is implemented instead of it if `compression(min_ratio = ..)` is set.
+///
+/// The compressed content is stored only if it is at least `min_ratio` times smaller than the original one.
+pub trait MaybeGzipContent {
+    /// Returns the compressed content in Gzip format or `None` if it is not worth storing.
+    fn gzip_content(&self) -> Option<&'static [u8]>;
+}
+
 #[cfg(feature = "brotli")]
 /// Trait providing access to content compressed in Brotli format.
 ///
@@ -56,6 +65,15 @@ pub trait BrotliContent {
     fn brotli_content(&self) -> &'static [u8];
 }
 
+#[cfg(feature = "brotli")]
+/// The same as [`BrotliContent`], but it is implemented instead of it if `compression(min_ratio = ..)` is set.
+///
+/// The compressed content is stored only if it is at least `min_ratio` times smaller than the original one.
+pub trait MaybeBrotliContent {
+    /// Returns the compressed content in Brotli format or `None` if it is not worth storing.
+    fn brotli_content(&self) -> Option<&'static [u8]>;
+}
+
 #[cfg(feature = "zstd")]
 /// Trait providing access to content compressed in Zstd format.
 ///
@@ -83,3 +101,12 @@ pub trait ZstdContent {
     /// Returns the compressed content in Zstd format.
     fn zstd_content(&self) -> &'static [u8];
 }
+
+#[cfg(feature = "zstd")]
+/// The same as [`ZstdContent`], but it is implemented instead of it if `compression(min_ratio = ..)` is set.
+///
+/// The compressed content is stored only if it is at least `min_ratio` times smaller than the original one.
+pub trait MaybeZstdContent {
+    /// Returns the compressed content in Zstd format or `None` if it is not worth storing.
+    fn zstd_content(&self) -> Option<&'static [u8]>;
+}
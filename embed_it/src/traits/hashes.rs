@@ -52,6 +52,16 @@ pub trait Sha2_512Hash {
     fn sha2_512(&self) -> &'static [u8; 64];
 }
 
+#[cfg(feature = "sha2")]
+/// A trait for accessing the Subresource Integrity string of a file
+pub trait Integrity {
+    /// Returns the Subresource Integrity string of a file, e.g. `sha384-<base64 of the hash>`,
+    /// which can be used as the `integrity` attribute of `<script>` and `<link>` tags
+    ///
+    /// If implementation is generated by the macro, this value is `const` and computed at compile time
+    fn integrity(&self) -> &'static str;
+}
+
 #[cfg(feature = "sha3")]
 /// A trait for accessing the sha3 224 hash of an entry
 pub trait Sha3_224Hash {
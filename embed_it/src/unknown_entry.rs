@@ -0,0 +1,13 @@
+use core::fmt::Display;
+
+/// An error of parsing an enum generated with `file_enum` or `dir_enum`: there is no embedded entry with the path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownEntry;
+
+impl Display for UnknownEntry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("unknown embedded entry")
+    }
+}
+
+impl core::error::Error for UnknownEntry {}
@@ -0,0 +1,154 @@
+use core::{iter::FusedIterator, mem::MaybeUninit};
+
+use crate::Entry;
+
+/// An iterator over children of a dir in breadth-first order: direct children first,
+/// then children of the first level directories and so on. It is returned by [`crate::Walk`]
+///
+/// It iterates the depth-first slice once per level, so it never allocates
+#[derive(Debug, Clone)]
+pub struct BreadthFirst<Dir: 'static, File: 'static> {
+    entries: &'static [Entry<Dir, File>],
+    depths: &'static [u16],
+    depth: u16,
+    pos: usize,
+
+    /// An entry deeper than the current level is passed
+    deeper: bool,
+}
+
+impl<Dir, File> BreadthFirst<Dir, File> {
+    /// Creates an iterator over entries in depth-first order with their depths,
+    /// e.g. a subslice of a generated flattened table of an embed
+    pub const fn new(entries: &'static [Entry<Dir, File>], depths: &'static [u16]) -> Self {
+        assert!(entries.len() == depths.len());
+        let depth = match depths.first() {
+            Some(depth) => *depth,
+            None => 0,
+        };
+        Self {
+            entries,
+            depths,
+            depth,
+            pos: 0,
+            deeper: false,
+        }
+    }
+}
+
+impl<Dir, File> Iterator for BreadthFirst<Dir, File> {
+    type Item = &'static Entry<Dir, File>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while let Some(&depth) = self.depths.get(self.pos) {
+                let pos = self.pos;
+                self.pos += 1;
+                if depth == self.depth {
+                    return Some(&self.entries[pos]);
+                }
+                self.deeper |= depth > self.depth;
+            }
+            if !self.deeper {
+                return None;
+            }
+            self.depth += 1;
+            self.pos = 0;
+            self.deeper = false;
+        }
+    }
+}
+
+impl<Dir, File> FusedIterator for BreadthFirst<Dir, File> {}
+
+/// Joins parts of a flattened table into an array at compile time.
+/// It is used by the generated code to include tables of mounted embeds
+#[doc(hidden)]
+pub const fn concat<T: Copy, const N: usize>(parts: &[&[T]]) -> [T; N] {
+    let mut result = [const { MaybeUninit::<T>::uninit() }; N];
+    let mut len = 0;
+    let mut part = 0;
+    while part < parts.len() {
+        let mut idx = 0;
+        while idx < parts[part].len() {
+            result[len] = MaybeUninit::new(parts[part][idx]);
+            len += 1;
+            idx += 1;
+        }
+        part += 1;
+    }
+    assert!(
+        len == N,
+        "the length of the parts differs from the length of the table"
+    );
+
+    // SAFETY: all `N` items are initialized
+    unsafe { (&raw const result).cast::<[T; N]>().read() }
+}
+
+/// Joins parts of a depth table like [`concat`], every depth of a part is increased by its offset
+#[doc(hidden)]
+pub const fn concat_depths<const N: usize>(parts: &[(&[u16], u16)]) -> [u16; N] {
+    let mut result = [0; N];
+    let mut len = 0;
+    let mut part = 0;
+    while part < parts.len() {
+        let (depths, offset) = parts[part];
+        let mut idx = 0;
+        while idx < depths.len() {
+            result[len] = depths[idx] + offset;
+            len += 1;
+            idx += 1;
+        }
+        part += 1;
+    }
+    assert!(
+        len == N,
+        "the length of the parts differs from the length of the table"
+    );
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Entry;
+
+    use super::{BreadthFirst, concat, concat_depths};
+
+    // a
+    // ├── b
+    // │   └── c
+    // └── d
+    // e
+    const ENTRIES: &[Entry<u8, u8>] = &[
+        Entry::Dir(b'a'),
+        Entry::Dir(b'b'),
+        Entry::File(b'c'),
+        Entry::File(b'd'),
+        Entry::File(b'e'),
+    ];
+    const DEPTHS: &[u16] = &[1, 2, 3, 2, 1];
+
+    fn names(iter: BreadthFirst<u8, u8>) -> String {
+        iter.map(|e| char::from(e.value())).collect()
+    }
+
+    #[test]
+    fn breadth_first() {
+        assert_eq!(names(BreadthFirst::new(ENTRIES, DEPTHS)), "aebdc");
+        assert_eq!(
+            names(BreadthFirst::new(&ENTRIES[1..4], &DEPTHS[1..4])),
+            "bdc"
+        );
+        assert_eq!(names(BreadthFirst::new(&[], &[])), "");
+    }
+
+    #[test]
+    fn concat_parts() {
+        const JOINED: [u8; 5] = concat(&[&[1, 2], &[], &[3, 4, 5]]);
+        assert_eq!(JOINED, [1, 2, 3, 4, 5]);
+
+        const DEPTHS: [u16; 4] = concat_depths(&[(&[1, 2], 0), (&[1, 1], 2)]);
+        assert_eq!(DEPTHS, [1, 2, 3, 3]);
+    }
+}
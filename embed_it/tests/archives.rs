@@ -0,0 +1,121 @@
+#![cfg(any(feature = "tar", feature = "zip"))]
+
+use std::time::{Duration, SystemTime};
+
+/// 2024-02-29 13:14:16 UTC, the modification time of all entries in the archives
+const MODIFIED: Duration = Duration::from_secs(1709212456);
+
+#[cfg(feature = "tar")]
+mod tar {
+    use embed_it::Entry;
+
+    use super::{MODIFIED, SystemTime};
+
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/archives/assets.tar.gz",
+        file(derive(StrContent))
+    )]
+    pub struct Assets;
+
+    #[test]
+    fn tree() {
+        assert_eq!(Assets.hello().content(), b"hello");
+        assert_eq!(Assets.one_txt().world().str_content(), "world");
+        assert_eq!(
+            Assets.one_txt().world().path().relative_path_str(),
+            "one_txt/world"
+        );
+        assert_eq!(Assets.entries().len(), 4);
+    }
+
+    #[test]
+    fn index() {
+        let Some(Entry::File(file)) = Assets.get("one_txt/hello") else {
+            panic!("a file is expected");
+        };
+        assert_eq!(file.content(), b"hello");
+        assert!(Assets.get("one_txt").is_some());
+        assert!(Assets.get("missing.txt").is_none());
+    }
+
+    #[test]
+    fn metadata_from_headers() {
+        let modified = SystemTime::UNIX_EPOCH + MODIFIED;
+        assert_eq!(Assets.hello().metadata().modified(), Some(modified));
+        assert_eq!(Assets.one_txt().metadata().modified(), Some(modified));
+        assert_eq!(Assets.hello().metadata().created(), None);
+    }
+
+    #[cfg(feature = "sha2")]
+    mod hashes {
+        mod archived {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = "$CARGO_MANIFEST_DIR/../example_dirs/archives/assets.tar.gz",
+                dir(derive(Sha2_256)),
+                file(derive(Sha2_256))
+            )]
+            pub struct Assets;
+        }
+
+        mod dir {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+                dir(derive(Sha2_256)),
+                file(derive(Sha2_256))
+            )]
+            pub struct Assets;
+        }
+
+        #[test]
+        fn the_same_as_for_a_dir() {
+            assert_eq!(archived::Assets.sha2_256(), dir::Assets.sha2_256());
+            assert_eq!(
+                archived::Assets.hello().sha2_256(),
+                dir::Assets.hello().sha2_256()
+            );
+        }
+    }
+}
+
+#[cfg(feature = "zip")]
+mod zip {
+    use super::{MODIFIED, SystemTime};
+
+    #[derive(embed_it::Embed)]
+    #[embed(path = "$CARGO_MANIFEST_DIR/../example_dirs/archives/assets.zip")]
+    pub struct Assets;
+
+    #[test]
+    fn tree() {
+        assert_eq!(Assets.one().content(), b"one");
+        assert_eq!(Assets.one_txt().hello().content(), b"hello");
+        assert!(Assets.get("world.txt").is_some());
+    }
+
+    #[test]
+    fn metadata_from_headers() {
+        let modified = SystemTime::UNIX_EPOCH + MODIFIED;
+        assert_eq!(Assets.world().metadata().modified(), Some(modified));
+
+        // the dir is not in the archive, it is a parent of files only
+        assert_eq!(Assets.one_txt().metadata().modified(), None);
+    }
+
+    mod overlay {
+        #[derive(embed_it::Embed)]
+        #[embed(
+            path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+            path = "$CARGO_MANIFEST_DIR/../example_dirs/archives/assets.zip"
+        )]
+        pub struct Assets;
+
+        #[test]
+        fn of_a_dir() {
+            assert_eq!(Assets.hello().path().root(), 1);
+            assert_eq!(Assets.one_txt().hello().path().root(), 1);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+#[derive(embed_it::Embed)]
+#[embed(
+    path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+    manifest = "$CARGO_TARGET_TMPDIR/build_manifest/assets.json"
+)]
+pub struct Assets;
+
+#[test]
+fn written_at_compile_time() {
+    let path = concat!(env!("CARGO_TARGET_TMPDIR"), "/build_manifest/assets.json");
+    let manifest: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+
+    assert_eq!(manifest["struct"], "Assets");
+    let paths = manifest["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["path"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["one_txt", "hello.txt", "one.txt", "world.txt"]);
+
+    let one_txt = &manifest["entries"][0];
+    assert_eq!(one_txt["kind"], "dir");
+    assert_eq!(one_txt["entries"][1]["path"], "one_txt/world");
+    assert_eq!(one_txt["entries"][1]["struct"], "World");
+    assert_eq!(one_txt["entries"][1]["field"], "world");
+    assert_eq!(one_txt["entries"][1]["size"], 5);
+    assert_eq!(
+        one_txt["entries"][1]["traits"],
+        serde_json::json!(["Content", "Debug", "EntryPath", "Meta"])
+    );
+}
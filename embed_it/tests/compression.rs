@@ -68,3 +68,81 @@ pub mod zstd {
         );
     }
 }
+
+#[cfg(all(feature = "zstd", feature = "gzip", feature = "brotli"))]
+pub mod configured {
+    use hex_literal::hex;
+
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        file(
+            derive_default_traits = false,
+            derive(Zstd),
+            derive(Gzip),
+            derive(Brotli),
+            compression(
+                zstd(level = 3, long_distance_matching, window_log = 20),
+                gzip(level = 1),
+                brotli(quality = 5, window = 20),
+            ),
+        ),
+        dir(derive_default_traits = false)
+    )]
+    pub struct Assets;
+
+    #[test]
+    fn check() {
+        // the fastest level is marked in the gzip header
+        assert_eq!(
+            Assets.hello().gzip_content(),
+            &hex!("1f8b08000000000004ffcb48cdc9c9070086a6103605000000")
+        );
+
+        // the window size is written in the frame and stream headers
+        assert_eq!(
+            Assets.hello().zstd_content(),
+            &hex!("28b52ffd005029000068656c6c6f")
+        );
+
+        assert_eq!(Assets.hello().brotli_content(), &hex!("07028068656c6c6f03"));
+    }
+}
+
+#[cfg(all(feature = "zstd", feature = "gzip", feature = "brotli"))]
+pub mod min_ratio {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/compressible",
+        file(
+            derive(Zstd),
+            derive(Gzip),
+            derive(Brotli),
+            compression(min_ratio = 1.5),
+        )
+    )]
+    pub struct Assets;
+
+    #[test]
+    fn check() {
+        let lorem = Assets.lorem();
+        assert!(lorem.gzip_content().unwrap().len() < lorem.content().len());
+        assert!(lorem.zstd_content().is_some());
+        assert!(lorem.brotli_content().is_some());
+
+        let tiny = Assets.tiny();
+        assert_eq!(tiny.gzip_content(), None);
+        assert_eq!(tiny.zstd_content(), None);
+        assert_eq!(tiny.brotli_content(), None);
+    }
+
+    #[test]
+    fn as_trait() {
+        fn gzip<T: File + ?Sized>(file: &T) -> Option<&'static [u8]> {
+            file.gzip_content()
+        }
+
+        assert!(gzip(Assets.lorem()).is_some());
+        assert!(gzip(Assets.tiny()).is_none());
+    }
+}
@@ -0,0 +1,79 @@
+pub mod dev {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        dev_mode = "all()",
+        file(derive(StrContent))
+    )]
+    pub struct Assets;
+}
+
+pub mod embedded {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        dev_mode = "any()",
+        file(derive(StrContent))
+    )]
+    pub struct Assets;
+}
+
+mod tests {
+    use embed_it::EmbeddedPath;
+
+    use super::{dev, embedded};
+
+    #[test]
+    fn content() {
+        assert_eq!(dev::Assets.hello().content(), b"hello");
+        assert_eq!(dev::Assets.hello().str_content(), "hello");
+        assert_eq!(dev::Assets.one_txt().world().content(), b"world");
+        assert_eq!(dev::Assets.one_txt().world().str_content(), "world");
+
+        assert_eq!(embedded::Assets.hello().content(), b"hello");
+        assert_eq!(embedded::Assets.hello().str_content(), "hello");
+    }
+
+    #[test]
+    fn dev_mode_reads_from_disk() {
+        // A content which is read from the disk is never placed in the same memory
+        assert_ne!(
+            dev::Assets.hello().content().as_ptr(),
+            embedded::Assets.hello().content().as_ptr()
+        );
+        assert_eq!(
+            dev::Assets.hello().content().as_ptr(),
+            dev::Assets.hello().content().as_ptr()
+        );
+    }
+
+    #[test]
+    fn index_and_entries() {
+        let file = dev::Assets.get("one_txt/hello").unwrap().file().unwrap();
+        assert_eq!(file.content(), b"hello");
+        assert_eq!(
+            file.path(),
+            &EmbeddedPath::new("one_txt/hello", "hello", "hello")
+        );
+
+        let dev_paths = dev::Assets
+            .entries()
+            .iter()
+            .map(|e| {
+                e.map(|d| d.path(), |f| f.path())
+                    .value()
+                    .relative_path_str()
+            })
+            .collect::<Vec<_>>();
+        let embedded_paths = embedded::Assets
+            .entries()
+            .iter()
+            .map(|e| {
+                e.map(|d| d.path(), |f| f.path())
+                    .value()
+                    .relative_path_str()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(dev_paths, embedded_paths);
+    }
+}
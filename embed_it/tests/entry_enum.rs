@@ -0,0 +1,72 @@
+use std::str::FromStr;
+
+use embed_it::{Entry, UnknownEntry};
+
+#[derive(embed_it::Embed)]
+#[embed(
+    path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+    file_enum,
+    dir_enum = "AssetDir"
+)]
+pub struct Assets;
+
+fn path(entry: Entry<DynDir, DynFile>) -> &'static str {
+    match entry {
+        Entry::Dir(d) => d.path().relative_path_str(),
+        Entry::File(f) => f.path().relative_path_str(),
+    }
+}
+
+#[test]
+fn file_enum() {
+    assert_eq!(
+        AssetsFile::ALL,
+        &[
+            AssetsFile::OneTxtHello,
+            AssetsFile::OneTxtWorld,
+            AssetsFile::HelloTxt,
+            AssetsFile::OneTxt,
+            AssetsFile::WorldTxt,
+        ]
+    );
+    for file in AssetsFile::ALL {
+        assert_eq!(path(file.entry()), file.as_str());
+        assert_eq!(AssetsFile::from_str(file.as_str()), Ok(*file));
+    }
+
+    assert_eq!(AssetsFile::HelloTxt.as_str(), "hello.txt");
+    assert_eq!(
+        AssetsFile::from_str("one_txt/hello"),
+        Ok(AssetsFile::OneTxtHello)
+    );
+    assert_eq!(AssetsFile::from_str("one_txt"), Err(UnknownEntry));
+    assert_eq!(AssetsFile::from_str("missing.txt"), Err(UnknownEntry));
+
+    let Entry::File(file) = Entry::from(AssetsFile::HelloTxt) else {
+        panic!("a file is expected");
+    };
+    assert_eq!(file.content(), b"hello");
+}
+
+#[test]
+fn dir_enum() {
+    assert_eq!(AssetDir::ALL, &[AssetDir::OneTxt]);
+    assert_eq!(AssetDir::OneTxt.as_str(), "one_txt");
+    assert_eq!("one_txt".parse(), Ok(AssetDir::OneTxt));
+    assert_eq!("".parse::<AssetDir>(), Err(UnknownEntry));
+    assert!(matches!(AssetDir::OneTxt.entry(), Entry::Dir(_)));
+}
+
+#[test]
+fn exhaustive() {
+    fn greeting(file: AssetsFile) -> Option<&'static str> {
+        match file {
+            AssetsFile::HelloTxt | AssetsFile::OneTxtHello => Some("hello"),
+            AssetsFile::WorldTxt | AssetsFile::OneTxtWorld => Some("world"),
+            AssetsFile::OneTxt => None,
+        }
+    }
+
+    assert_eq!(greeting(AssetsFile::OneTxtHello), Some("hello"));
+    assert_eq!(greeting(AssetsFile::OneTxt), None);
+}
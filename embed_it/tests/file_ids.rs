@@ -0,0 +1,44 @@
+use embed_it::Entry;
+
+#[derive(embed_it::Embed)]
+#[embed(path = "$CARGO_MANIFEST_DIR/../example_dirs/assets", file_ids)]
+pub struct Assets;
+
+#[test]
+fn ids() {
+    assert_eq!(hello::Hello::ID, 0);
+    assert_eq!(one::One::ID, 1);
+    assert_eq!(one_txt::hello::Hello::ID, 2);
+    assert_eq!(one_txt::world::World::ID, 3);
+    assert_eq!(world::World::ID, 4);
+}
+
+#[test]
+fn files() {
+    let paths = Assets::FILES
+        .iter()
+        .map(|f| f.path().relative_path_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        vec![
+            "hello.txt",
+            "one.txt",
+            "one_txt/hello",
+            "one_txt/world",
+            "world.txt"
+        ]
+    );
+}
+
+#[test]
+fn by_id() {
+    let Some(Entry::File(file)) = Assets::by_id(one_txt::world::World::ID) else {
+        panic!("a file is expected");
+    };
+    assert_eq!(file.path().relative_path_str(), "one_txt/world");
+    assert_eq!(file.content(), b"world");
+
+    assert!(Assets::by_id(5).is_none());
+    assert!(Assets::by_id(u32::MAX).is_none());
+}
@@ -0,0 +1,68 @@
+use embed_it::{Entry, Found};
+
+#[derive(embed_it::Embed)]
+#[embed(path = "$CARGO_MANIFEST_DIR/../example_dirs/assets", dir(derive(Find)))]
+pub struct Assets;
+
+fn paths(found: impl Iterator<Item = &'static Entry<DynDir, DynFile>>) -> Vec<&'static str> {
+    found
+        .map(|e| match e {
+            Entry::Dir(d) => d.path().relative_path_str(),
+            Entry::File(f) => f.path().relative_path_str(),
+        })
+        .collect()
+}
+
+#[test]
+fn relative_entries() {
+    let relative = Assets
+        .one_txt()
+        .relative_entries()
+        .map(|(path, _)| path)
+        .collect::<Vec<_>>();
+    assert_eq!(relative, vec!["hello", "world"]);
+    assert_eq!(Assets.relative_entries().len(), 6);
+}
+
+#[test]
+fn custom_matcher() {
+    assert_eq!(
+        paths(Found::new(Assets.relative_entries(), |p: &str| p.starts_with("one"))),
+        vec!["one_txt", "one_txt/hello", "one_txt/world", "one.txt"]
+    );
+}
+
+#[test]
+#[cfg(feature = "glob")]
+fn glob() {
+    use embed_it::Find;
+
+    assert_eq!(
+        paths(Assets.glob("one_txt/*").unwrap()),
+        vec!["one_txt/hello", "one_txt/world"]
+    );
+    assert_eq!(
+        paths(Assets.glob("*.txt").unwrap()),
+        vec!["hello.txt", "one.txt", "world.txt"]
+    );
+    assert_eq!(
+        paths(Assets.one_txt().glob("w*").unwrap()),
+        vec!["one_txt/world"]
+    );
+    assert!(Assets.glob("***").is_err());
+
+    let dir: &dyn Find<DynDir, DynFile> = &Assets;
+    assert_eq!(dir.glob("**/hello").unwrap().count(), 1);
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn regex() {
+    use embed_it::Find;
+
+    assert_eq!(
+        paths(Assets.regex(r"^[^/]+/w").unwrap()),
+        vec!["one_txt/world"]
+    );
+    assert!(Assets.regex("(").is_err());
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "sha2")]
+
+mod default {
+    use embed_it::Entry;
+
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        dir(derive(Index)),
+        file(derive(HashedName))
+    )]
+    pub struct Assets;
+
+    #[test]
+    fn hashed_path() {
+        assert_eq!(Assets.hello().hashed_path(), "hello.2cf24dba.txt");
+        assert_eq!(Assets.world().hashed_path(), "world.486ea462.txt");
+        assert_eq!(
+            Assets.one_txt().hello().hashed_path(),
+            "one_txt/hello.2cf24dba"
+        );
+        assert_eq!(
+            Assets.one_txt().world().hashed_path(),
+            "one_txt/world.486ea462"
+        );
+    }
+
+    #[test]
+    fn index() {
+        let Some(Entry::File(file)) = Assets.get("hello.2cf24dba.txt") else {
+            panic!("hashed path is not resolved");
+        };
+        assert_eq!(file.path().relative_path_str(), "hello.txt");
+
+        let Some(Entry::File(file)) = Assets.get("one_txt/world.486ea462") else {
+            panic!("nested hashed path is not resolved");
+        };
+        assert_eq!(file.path().relative_path_str(), "one_txt/world");
+
+        let Some(Entry::File(file)) = Assets.one_txt().get("world.486ea462") else {
+            panic!("hashed path is not resolved by a nested dir");
+        };
+        assert_eq!(file.path().relative_path_str(), "one_txt/world");
+
+        // the original path leads to the hashed one
+        let Some(Entry::File(file)) = Assets.get("one_txt/hello") else {
+            panic!("original path is not resolved");
+        };
+        assert_eq!(file.hashed_path(), "one_txt/hello.2cf24dba");
+
+        assert!(Assets.get("hello.00000000.txt").is_none());
+    }
+}
+
+mod sorted {
+    use embed_it::Entry;
+
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        index(kind = "sorted"),
+        dir(derive(Index)),
+        file(derive(HashedName), hashed_name(len = 12))
+    )]
+    pub struct Assets;
+
+    #[test]
+    fn index() {
+        assert_eq!(Assets.one().hashed_path(), "one.7692c3ad3540.txt");
+        let Some(Entry::File(file)) = Assets.get("one.7692c3ad3540.txt") else {
+            panic!("hashed path is not resolved");
+        };
+        assert_eq!(file.path().relative_path_str(), "one.txt");
+    }
+}
+
+#[cfg(feature = "md5")]
+mod md5 {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        file(derive(HashedName), derive(Md5), hashed_name(hash = "md5", len = 6))
+    )]
+    pub struct Assets;
+
+    #[test]
+    fn hashed_path() {
+        assert_eq!(Assets.hello().hashed_path(), "hello.5d4140.txt");
+    }
+}
@@ -0,0 +1,92 @@
+macro_rules! path_helper {
+    () => {
+        fn path(entry: Option<&embed_it::Entry<DynDir, DynFile>>) -> Option<&'static str> {
+            entry.map(|e| match e {
+                embed_it::Entry::Dir(d) => d.path().relative_path_str(),
+                embed_it::Entry::File(f) => f.path().relative_path_str(),
+            })
+        }
+    };
+}
+
+macro_rules! lookup_tests {
+    () => {
+        path_helper!();
+
+        #[test]
+        fn normalized() {
+            assert_eq!(
+                path(Assets.get("/Public/./css//main.css")),
+                Some("Public/css/Main.css")
+            );
+            assert_eq!(
+                path(Assets.get("public/CSS/MAIN.CSS")),
+                Some("Public/css/Main.css")
+            );
+            assert_eq!(path(Assets.get("./public/css/")), Some("Public/css"));
+            assert_eq!(
+                path(Assets.get("docs/read%20me.txt")),
+                Some("docs/read me.txt")
+            );
+            assert_eq!(path(Assets.get("/")), Some(""));
+            assert_eq!(
+                path(Assets.public().get("CSS/main.css")),
+                Some("Public/css/Main.css")
+            );
+        }
+
+        #[test]
+        fn rejected() {
+            assert_eq!(path(Assets.get("public/../docs/read me.txt")), None);
+            assert_eq!(path(Assets.get("public/%2e%2e/docs/read me.txt")), None);
+            assert_eq!(path(Assets.get("public/css/main.cs")), None);
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+mod hash_map {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/lookup",
+        index(kind = "hash_map", case_insensitive, normalize_path, percent_decode),
+        dir(derive(Index))
+    )]
+    pub struct Assets;
+
+    lookup_tests!();
+}
+
+mod sorted {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/lookup",
+        index(kind = "sorted", case_insensitive, normalize_path, percent_decode),
+        dir(derive(Index))
+    )]
+    pub struct Assets;
+
+    lookup_tests!();
+}
+
+mod only_normalize_path {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/lookup",
+        index(normalize_path),
+        dir(derive(Index))
+    )]
+    pub struct Assets;
+
+    path_helper!();
+
+    #[test]
+    fn normalized() {
+        assert_eq!(
+            path(Assets.get("//Public/css/./Main.css")),
+            Some("Public/css/Main.css")
+        );
+        assert_eq!(path(Assets.get("public/css/main.css")), None);
+        assert_eq!(path(Assets.get("docs/read%20me.txt")), None);
+    }
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "sha2")]
+
+mod sha2_384 {
+    use embed_it::Integrity;
+
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        file(derive(Sri(Sha2_384)))
+    )]
+    pub struct Assets;
+
+    #[test]
+    fn check_integrity() {
+        assert_eq!(
+            Assets.hello().integrity(),
+            "sha384-WeF0h3dEjGnea4ANejO7+5/xtGPkQ1TDVTvNucZm+pASWjx5+QOXvfX2oT3oKGhP"
+        );
+        assert_eq!(
+            Assets.world().integrity(),
+            "sha384-pNECuyo5tvHZ5IHvGha4lIoN8rWU/QMbrW8gH71rBlaEam5Yowqlf/NNkS59PqGF"
+        );
+        assert_eq!(
+            Assets.one_txt().hello().integrity(),
+            Assets.hello().integrity()
+        );
+
+        let file: &dyn Integrity = Assets.world();
+        assert_eq!(
+            file.integrity(),
+            "sha384-pNECuyo5tvHZ5IHvGha4lIoN8rWU/QMbrW8gH71rBlaEam5Yowqlf/NNkS59PqGF"
+        );
+    }
+}
+
+mod with_hash {
+    use hex_literal::hex;
+
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        file(derive(Sha2_384), derive(Sri(Sha2_384)))
+    )]
+    pub struct Assets;
+
+    #[test]
+    fn check_integrity() {
+        assert_eq!(
+            Assets.hello().integrity(),
+            "sha384-WeF0h3dEjGnea4ANejO7+5/xtGPkQ1TDVTvNucZm+pASWjx5+QOXvfX2oT3oKGhP"
+        );
+        assert_eq!(
+            Assets.hello().sha2_384(),
+            &hex!(
+                "59e1748777448c69de6b800d7a33bbfb9ff1b463e44354c3553bcdb9c666fa90125a3c79f90397bdf5f6a13de828684f"
+            )
+        );
+    }
+}
+
+mod sha2_256 {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        file(derive(Sri(Sha2_256)))
+    )]
+    pub struct Assets;
+
+    #[test]
+    fn check_integrity() {
+        assert_eq!(
+            Assets.hello().integrity(),
+            "sha256-LPJNul+wow4m6DsqxbninhsWHlwfp0JecwQzYpOLmCQ="
+        );
+    }
+}
+
+mod sha2_512 {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        file(derive(Sri(Sha2_512)))
+    )]
+    pub struct Assets;
+
+    #[test]
+    fn check_integrity() {
+        assert_eq!(
+            Assets.hello().integrity(),
+            "sha512-m3HSJL1i83hdltRq0+o9czGb+8KJDKra4t/3JRlnPKcjI8PZm6XBHXx6zG4UuMXaDEZjR1wuXDre9G9zvN7AQw=="
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use embed_it::{Entry, Manifest, ManifestEntry};
+
+#[derive(embed_it::Embed)]
+#[embed(
+    path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+    dir(derive_default_traits = false, derive(Manifest)),
+    file(derive_default_traits = false, derive(Manifest))
+)]
+pub struct Assets;
+
+fn paths(entry: ManifestEntry, paths: &mut Vec<&'static str>) {
+    match entry {
+        Entry::Dir(dir) => {
+            paths.push(dir.path.relative_path_str());
+            for child in dir.entries {
+                self::paths(*child, paths);
+            }
+        }
+        Entry::File(file) => paths.push(file.path.relative_path_str()),
+    }
+}
+
+#[test]
+fn walks_entries() {
+    let mut actual = Vec::new();
+    paths(Assets.manifest(), &mut actual);
+    assert_eq!(
+        actual,
+        vec![
+            "",
+            "one_txt",
+            "one_txt/hello",
+            "one_txt/world",
+            "hello.txt",
+            "one.txt",
+            "world.txt"
+        ]
+    );
+}
+
+#[test]
+fn files() {
+    let Entry::File(file) = hello::Hello.manifest() else {
+        panic!("a file is expected");
+    };
+    assert_eq!(file.path.name(), "hello.txt");
+    assert_eq!(file.size, 5);
+    assert!(file.hashes.is_empty());
+}
+
+#[test]
+fn is_const() {
+    const MANIFEST: ManifestEntry = Assets.manifest();
+    assert_eq!(MANIFEST, Manifest::manifest(&Assets));
+}
+
+#[cfg(feature = "sha2")]
+mod hashes {
+    use embed_it::{Entry, ManifestHash};
+
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        dir(derive(Sha2_256), derive(Manifest)),
+        file(derive(Manifest), derive(Sha2_256))
+    )]
+    pub struct Assets;
+
+    #[test]
+    fn hex_of_derived_hashes() {
+        let Entry::File(file) = Assets.hello().manifest() else {
+            panic!("a file is expected");
+        };
+        assert_eq!(
+            file.hashes,
+            &[ManifestHash {
+                name: "sha2_256",
+                hex: "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+            }]
+        );
+
+        let Entry::Dir(dir) = Assets.one_txt().manifest() else {
+            panic!("a dir is expected");
+        };
+        let hex = Assets
+            .one_txt()
+            .sha2_256()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect::<String>();
+        assert_eq!(dir.hashes[0].hex, hex);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use embed_it::Entry;
+
+    use super::{Assets, one_txt};
+
+    #[test]
+    fn serialize() {
+        let Entry::Dir(dir) = one_txt::OneTxt.manifest() else {
+            panic!("a dir is expected");
+        };
+        let mut json = serde_json::to_value(one_txt::OneTxt.manifest()).unwrap();
+
+        // metadata depends on the checkout
+        json["Dir"]["metadata"] = serde_json::Value::Null;
+        for child in json["Dir"]["entries"].as_array_mut().unwrap() {
+            child["File"]["metadata"] = serde_json::Value::Null;
+        }
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "Dir": {
+                    "path": { "relative_path": "one_txt", "name": "one_txt", "stem": "one_txt" },
+                    "metadata": null,
+                    "hashes": {},
+                    "entries": [
+                        {
+                            "File": {
+                                "path": { "relative_path": "one_txt/hello", "name": "hello", "stem": "hello" },
+                                "metadata": null,
+                                "size": 5,
+                                "hashes": {}
+                            }
+                        },
+                        {
+                            "File": {
+                                "path": { "relative_path": "one_txt/world", "name": "world", "stem": "world" },
+                                "metadata": null,
+                                "size": 5,
+                                "hashes": {}
+                            }
+                        }
+                    ]
+                }
+            })
+        );
+        assert_eq!(dir.entries.len(), 2);
+    }
+
+    #[test]
+    fn serialize_metadata() {
+        let json = serde_json::to_value(Assets.manifest()).unwrap();
+        let modified = &json["Dir"]["metadata"]["modified"];
+        assert!(modified.is_null() || modified["secs"].is_u64());
+    }
+}
@@ -0,0 +1,48 @@
+mod by_extension {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        file(derive(Mime))
+    )]
+    pub struct Assets;
+}
+
+mod sniff {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        file(
+            derive(Mime),
+            mime(sniff, map(pattern = "one_txt/w*", value = "text/x-world"))
+        )
+    )]
+    pub struct Assets;
+}
+
+mod tests {
+    use embed_it::Mime;
+
+    use super::*;
+
+    #[test]
+    fn by_extension() {
+        use by_extension::*;
+        assert_eq!(Assets.hello().mime(), "text/plain; charset=utf-8");
+        assert_eq!(Assets.one_txt().hello().mime(), "application/octet-stream");
+        assert_eq!(Assets.one_txt().world().mime(), "application/octet-stream");
+    }
+
+    #[test]
+    fn sniff() {
+        use sniff::*;
+        assert_eq!(Assets.hello().mime(), "text/plain; charset=utf-8");
+        assert_eq!(Assets.one_txt().hello().mime(), "text/plain; charset=utf-8");
+        assert_eq!(Assets.one_txt().world().mime(), "text/x-world");
+    }
+
+    #[test]
+    fn as_trait() {
+        let file: &dyn Mime = by_extension::Assets.world();
+        assert_eq!(file.mime(), "text/plain; charset=utf-8");
+    }
+}
@@ -0,0 +1,116 @@
+use embed_it::Entry;
+
+mod assets {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        dir(
+            derive(Walk),
+            mount(path = "custom", embed = crate::custom::Custom),
+            mount(path = "one_txt/nested", embed = crate::nested_custom::NestedCustom),
+        )
+    )]
+    pub struct Assets;
+}
+
+mod custom {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/overlay/custom",
+        dir(derive(Walk)),
+        definitions = crate::assets,
+        mounted_at = "custom"
+    )]
+    pub struct Custom;
+}
+
+mod nested_custom {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/overlay/custom",
+        dir(derive(Walk)),
+        definitions = crate::assets,
+        mounted_at = "one_txt/nested"
+    )]
+    pub struct NestedCustom;
+}
+
+use std::collections::HashSet;
+
+use assets::{Assets, DynDir, DynFile};
+use custom::Custom;
+
+#[test]
+fn mounted_entries() {
+    assert_eq!(Assets.custom().hello().content(), b"hi");
+    assert_eq!(
+        Assets.one_txt().nested().theme().style().content(),
+        b"custom style"
+    );
+
+    let Some(Entry::Dir(last)) = Assets.entries().last() else {
+        panic!("the mount is expected to be the last entry");
+    };
+    assert_eq!(last.direct_child_count(), Custom.direct_child_count());
+    assert_eq!(Assets.direct_child_count(), 5);
+    assert_eq!(
+        Assets.recursive_child_count(),
+        8 + 2 * Custom.recursive_child_count()
+    );
+}
+
+#[test]
+fn get_delegates_to_mounted() {
+    let Some(Entry::File(style)) = Assets.get("custom/theme/style.css") else {
+        panic!("a file is expected");
+    };
+    assert_eq!(style.content(), b"custom style");
+    assert_eq!(style.path().relative_path_str(), "custom/theme/style.css");
+    assert!(matches!(Assets.get("custom"), Some(Entry::Dir(_))));
+    assert!(Assets.get("one_txt/nested/extra.txt").is_some());
+    assert!(Assets.one_txt().get("nested/hello.txt").is_some());
+    assert!(Assets.get("one_txt/hello").is_some());
+
+    assert!(Assets.get("custom/missing.txt").is_none());
+    assert!(Assets.get("customs/hello.txt").is_none());
+    assert!(Assets.get("nested/hello.txt").is_none());
+}
+
+fn path(entry: &Entry<DynDir, DynFile>) -> &'static str {
+    match entry {
+        Entry::Dir(d) => d.path().relative_path_str(),
+        Entry::File(f) => f.path().relative_path_str(),
+    }
+}
+
+#[test]
+fn walk_across_mounts() {
+    let walked = Assets.walk();
+    assert_eq!(walked.len(), Assets.recursive_child_count());
+    assert_eq!(
+        Assets.walk_breadth_first().count(),
+        Assets.recursive_child_count()
+    );
+    assert_eq!(Assets.one_txt().walk().len(), 2 + Custom.walk().len() + 1);
+
+    let styles = walked
+        .iter()
+        .filter_map(|e| e.file())
+        .filter(|f| f.content() == b"custom style")
+        .count();
+    assert_eq!(styles, 2);
+
+    // paths of mounted entries start with the mount point
+    let paths = walked.iter().map(path).collect::<Vec<_>>();
+    assert_eq!(paths.iter().collect::<HashSet<_>>().len(), paths.len());
+    assert!(paths.contains(&"custom/theme/style.css"));
+    assert!(paths.contains(&"one_txt/nested/theme/style.css"));
+
+    // the mount point is the root of the mounted embed and its subtree follows it
+    let Some(position) = paths.iter().position(|p| *p == "custom") else {
+        panic!("the mount point is expected to be walked");
+    };
+    let mounted = Custom.walk().iter().map(path).collect::<Vec<_>>();
+    assert!(mounted.iter().all(|p| p.starts_with("custom/")));
+    assert_eq!(paths[position + 1..][..mounted.len()], mounted);
+}
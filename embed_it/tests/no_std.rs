@@ -0,0 +1,44 @@
+//! The generated code must compile without `std` and `alloc`: neither of them
+//! can be resolved in this crate, so any `::std` or `::alloc` path fails the compilation.
+//! `std` is linked only to run `main`.
+#![no_std]
+
+extern crate std as _std;
+
+#[derive(embed_it::Embed)]
+#[embed(
+    path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+    support_alt_separator,
+    index(kind = "sorted"),
+    file(derive(StrContent)),
+    file_enum,
+    file_ids
+)]
+pub struct Assets;
+
+fn main() {
+    use embed_it::{EmbeddedPath, Entries, Index};
+
+    assert_eq!(Assets.hello().content(), b"hello");
+    assert_eq!(Assets.one_txt().world().str_content(), "world");
+    assert_eq!(
+        Assets.one_txt().hello().path(),
+        &EmbeddedPath::new("one_txt/hello", "hello", "hello")
+    );
+    assert!(Assets.one_txt().hello().metadata().modified.is_some());
+    assert_eq!(Assets.direct_child_count(), 4);
+    assert_eq!(Assets.recursive_child_count(), 6);
+
+    let file = Index::get(&Assets, "one_txt\\hello")
+        .unwrap()
+        .file()
+        .unwrap();
+    assert_eq!(file.content(), b"hello");
+    assert_eq!(Entries::entries(&Assets).len(), 4);
+    assert_eq!(
+        "one_txt/world".parse::<AssetsFile>(),
+        Ok(AssetsFile::OneTxtWorld)
+    );
+    assert_eq!(Assets::FILES.len(), 5);
+    assert!(Assets::by_id(one_txt::world::World::ID).is_some());
+}
@@ -0,0 +1,41 @@
+use embed_it::Entry;
+
+#[derive(embed_it::Embed)]
+#[embed(
+    path = "$CARGO_MANIFEST_DIR/../example_dirs/overlay/base",
+    path = "$CARGO_MANIFEST_DIR/../example_dirs/overlay/custom"
+)]
+pub struct Assets;
+
+#[test]
+fn later_root_shadows_files() {
+    assert_eq!(Assets.hello().content(), b"hi");
+    assert_eq!(Assets.hello().path().root(), 1);
+    assert_eq!(Assets.extra().path().root(), 1);
+}
+
+#[test]
+fn dirs_are_merged() {
+    let theme = Assets.theme();
+    assert_eq!(theme.path().root(), 1);
+    assert_eq!(theme.logo().content(), b"base logo");
+    assert_eq!(theme.logo().path().root(), 0);
+    assert_eq!(theme.style().content(), b"custom style");
+    assert_eq!(theme.style().path().root(), 1);
+
+    let names = Assets
+        .entries()
+        .iter()
+        .map(|e| e.map(|d| d.path(), |f| f.path()).value().name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["theme", "extra.txt", "hello.txt"]);
+}
+
+#[test]
+fn index() {
+    let Some(Entry::File(style)) = Assets.get("theme/style.css") else {
+        panic!("a file is expected");
+    };
+    assert_eq!(style.content(), b"custom style");
+    assert!(Assets.get("theme/logo.txt").is_some());
+}
@@ -0,0 +1,110 @@
+use embed_it::{Redirect, Resolved, Resolver, TrailingSlash};
+
+#[derive(embed_it::Embed)]
+#[embed(path = "$CARGO_MANIFEST_DIR/../example_dirs/spa")]
+pub struct Assets;
+
+fn resolve(resolver: &Resolver<'_>, path: &str) -> Option<(bool, &'static str)> {
+    match resolver.resolve(&Assets, path) {
+        Resolved::File(f) => Some((false, f.path().relative_path_str())),
+        Resolved::Fallback(f) => Some((true, f.path().relative_path_str())),
+        Resolved::Redirect(_) | Resolved::NotFound => None,
+    }
+}
+
+#[test]
+fn index_files() {
+    let resolver = Resolver::default();
+    assert_eq!(resolve(&resolver, ""), Some((false, "index.html")));
+    assert_eq!(resolve(&resolver, "/"), Some((false, "index.html")));
+    assert_eq!(
+        resolve(&resolver, "/docs"),
+        Some((false, "docs/index.html"))
+    );
+    assert_eq!(
+        resolve(&resolver, "/docs/"),
+        Some((false, "docs/index.html"))
+    );
+    assert_eq!(resolve(&resolver, "/app.js"), Some((false, "app.js")));
+    assert_eq!(resolve(&resolver, "/app.js/"), Some((false, "app.js")));
+    assert_eq!(resolve(&resolver, "/some/route"), None);
+
+    let resolver = Resolver {
+        index_files: &["missing.html", "app.js"],
+        ..Resolver::DEFAULT
+    };
+    assert_eq!(resolve(&resolver, "/"), Some((false, "app.js")));
+    assert_eq!(resolve(&resolver, "/docs/"), None);
+
+    let resolver = Resolver {
+        index_files: &[],
+        ..Resolver::DEFAULT
+    };
+    assert_eq!(resolve(&resolver, "/docs/"), None);
+}
+
+#[test]
+fn fallback() {
+    let resolver = Resolver {
+        fallback: Some("/index.html"),
+        ..Resolver::DEFAULT
+    };
+    assert_eq!(
+        resolve(&resolver, "/some/route"),
+        Some((true, "index.html"))
+    );
+    assert_eq!(
+        resolve(&resolver, "/docs"),
+        Some((false, "docs/index.html"))
+    );
+    assert_eq!(
+        resolve(
+            &Resolver {
+                index_files: &[],
+                ..resolver
+            },
+            "/docs"
+        ),
+        Some((true, "index.html"))
+    );
+
+    let resolver = Resolver {
+        fallback: Some("missing.html"),
+        ..Resolver::DEFAULT
+    };
+    assert!(matches!(
+        resolver.resolve(&Assets, "/some/route"),
+        Resolved::NotFound
+    ));
+}
+
+#[test]
+fn trailing_slash() {
+    let resolver = Resolver {
+        trailing_slash: TrailingSlash::Redirect,
+        ..Resolver::DEFAULT
+    };
+    assert!(matches!(
+        resolver.resolve(&Assets, "/docs"),
+        Resolved::Redirect(Redirect::AddSlash)
+    ));
+    assert!(matches!(
+        resolver.resolve(&Assets, "/app.js/"),
+        Resolved::Redirect(Redirect::RemoveSlash)
+    ));
+    assert_eq!(
+        resolve(&resolver, "/docs/"),
+        Some((false, "docs/index.html"))
+    );
+    assert_eq!(resolve(&resolver, "/app.js"), Some((false, "app.js")));
+    assert_eq!(resolve(&resolver, ""), Some((false, "index.html")));
+}
+
+#[test]
+fn nested() {
+    let resolver = Resolver::default();
+    let Resolved::File(file) = resolver.resolve(Assets.docs(), "/") else {
+        panic!("a file is expected");
+    };
+    assert_eq!(file.path().relative_path_str(), "docs/index.html");
+}
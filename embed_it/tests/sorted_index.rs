@@ -0,0 +1,91 @@
+mod sorted {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        index(kind = "sorted")
+    )]
+    pub struct Assets;
+}
+
+mod sorted_alt_sep {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        support_alt_separator,
+        index(kind = "sorted")
+    )]
+    pub struct Assets;
+}
+
+mod tests {
+    use embed_it::{EmbeddedPath, Index};
+
+    use super::*;
+
+    // `get` is evaluated at compile time
+    const _: () = assert!(sorted::Assets.get("one_txt/hello").is_some());
+    const _: () = assert!(sorted::Assets.get("one_txt/unknown").is_none());
+
+    #[test]
+    fn get() {
+        let paths = [
+            "hello.txt",
+            "one.txt",
+            "world.txt",
+            "one_txt",
+            "one_txt/hello",
+            "one_txt/world",
+        ];
+        for path in paths {
+            let entry = sorted::Assets
+                .get(path)
+                .unwrap_or_else(|| panic!("Unable to find '{path}'"));
+            assert_eq!(
+                entry
+                    .map(|d| d.path(), |f| f.path())
+                    .value()
+                    .relative_path_str(),
+                path
+            );
+        }
+
+        assert_eq!(
+            sorted::Assets.get("").unwrap().dir().unwrap().path(),
+            &EmbeddedPath::new("", "", "")
+        );
+        assert!(sorted::Assets.get("one_txt/").is_none());
+        assert!(sorted::Assets.get("one_txt\\hello").is_none());
+        assert_eq!(
+            sorted::Assets
+                .one_txt()
+                .get("hello")
+                .unwrap()
+                .file()
+                .unwrap()
+                .content(),
+            b"hello"
+        );
+    }
+
+    #[test]
+    fn get_with_alt_separator() {
+        assert_eq!(
+            sorted_alt_sep::Assets
+                .get("one_txt\\hello")
+                .unwrap()
+                .file()
+                .unwrap()
+                .content(),
+            b"hello"
+        );
+    }
+
+    #[test]
+    fn get_dyn() {
+        fn get<I: Index<sorted::DynDir, sorted::DynFile>>(index: &I, path: &str) -> bool {
+            index.get(path).is_some()
+        }
+        assert!(get(&sorted::Assets, "one_txt/world"));
+        assert!(!get(&sorted::Assets, "one_txt/unknown"));
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(feature = "zstd-decompress")]
+pub mod zstd {
+    use hex_literal::hex;
+
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        file(
+            derive(StrContent),
+            derive(Zstd),
+            compression(store_compressed = "zstd"),
+        )
+    )]
+    pub struct Assets;
+
+    #[test]
+    fn check() {
+        assert_eq!(Assets.hello().content(), b"hello");
+        assert_eq!(Assets.hello().str_content(), "hello");
+        assert_eq!(Assets.one_txt().world().content(), b"world");
+
+        // the same bytes are used for the pass-through serving
+        assert_eq!(
+            Assets.hello().zstd_content(),
+            &hex!("28b52ffd008829000068656c6c6f")
+        );
+        assert_eq!(
+            Assets.hello().stored_content().compressed(),
+            Assets.hello().zstd_content()
+        );
+    }
+}
+
+#[cfg(all(feature = "gzip-decompress", feature = "brotli"))]
+pub mod gzip_min_ratio {
+    use embed_it::Content;
+
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/compressible",
+        file(
+            derive(Gzip),
+            derive(Brotli),
+            compression(store_compressed = "gzip", min_ratio = 1.5),
+        )
+    )]
+    pub struct Assets;
+
+    #[test]
+    fn check() {
+        let lorem = Assets.lorem();
+        assert!(lorem.content().starts_with(b"Lorem ipsum"));
+        assert_eq!(lorem.content().len(), 16 * 124);
+        assert_eq!(
+            Some(lorem.stored_content().compressed()),
+            lorem.gzip_content()
+        );
+        assert!(lorem.brotli_content().is_some());
+
+        // it is not worth compressing, so it is embedded as is
+        let tiny: &dyn Content = Assets.tiny();
+        assert_eq!(tiny.content(), b"hi");
+        assert_eq!(Assets.tiny().gzip_content(), None);
+    }
+}
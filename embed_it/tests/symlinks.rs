@@ -0,0 +1,67 @@
+#![cfg(unix)]
+
+pub mod follow {
+    #[derive(embed_it::Embed)]
+    #[embed(path = "$CARGO_MANIFEST_DIR/../example_dirs/symlinks")]
+    pub struct Assets;
+
+    #[test]
+    fn embeds_targets() {
+        assert_eq!(Assets.link().content(), b"hello");
+        assert_eq!(Assets.dir_link().world().content(), b"world");
+        assert_eq!(Assets.entries().len(), 4);
+    }
+}
+
+pub mod skip {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/symlinks",
+        symlinks = "skip"
+    )]
+    pub struct Assets;
+
+    #[test]
+    fn skips_links() {
+        let names = Assets
+            .entries()
+            .iter()
+            .map(|e| e.map(|d| d.path(), |f| f.path()).value().name())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["dir", "hello.txt"]);
+    }
+}
+
+pub mod preserve {
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/symlinks",
+        symlinks = "preserve",
+        file(derive(LinkTarget), derive(StrContent))
+    )]
+    pub struct Assets;
+
+    #[test]
+    fn embeds_links_as_files() {
+        assert_eq!(Assets.link().link_target(), Some("hello.txt"));
+        assert_eq!(Assets.link().content(), b"hello.txt");
+        assert_eq!(Assets.link().str_content(), "hello.txt");
+
+        assert_eq!(Assets.dir_link().link_target(), Some("dir"));
+        assert_eq!(Assets.dir_link().content(), b"dir");
+
+        assert_eq!(Assets.hello().link_target(), None);
+        assert_eq!(Assets.hello().content(), b"hello");
+    }
+
+    #[test]
+    fn dyn_link_target() {
+        let targets = Assets
+            .entries()
+            .iter()
+            .filter_map(|e| e.as_ref().file())
+            .map(|f| f.link_target())
+            .collect::<Vec<_>>();
+        assert_eq!(targets, vec![Some("dir"), None, Some("hello.txt")]);
+    }
+}
@@ -0,0 +1,111 @@
+use embed_it::{Entry, Walk};
+
+#[derive(embed_it::Embed)]
+#[embed(path = "$CARGO_MANIFEST_DIR/../example_dirs/assets", dir(derive(Walk)))]
+pub struct Assets;
+
+fn paths(entries: impl IntoIterator<Item = &'static Entry<DynDir, DynFile>>) -> Vec<&'static str> {
+    entries
+        .into_iter()
+        .map(|e| match e {
+            Entry::Dir(d) => d.path().relative_path_str(),
+            Entry::File(f) => f.path().relative_path_str(),
+        })
+        .collect()
+}
+
+#[test]
+fn depth_first() {
+    assert_eq!(
+        paths(Assets.walk()),
+        vec![
+            "one_txt",
+            "one_txt/hello",
+            "one_txt/world",
+            "hello.txt",
+            "one.txt",
+            "world.txt",
+        ]
+    );
+    assert_eq!(
+        paths(Assets.one_txt().walk()),
+        vec!["one_txt/hello", "one_txt/world"]
+    );
+}
+
+#[test]
+fn breadth_first() {
+    assert_eq!(
+        paths(Assets.walk_breadth_first()),
+        vec![
+            "one_txt",
+            "hello.txt",
+            "one.txt",
+            "world.txt",
+            "one_txt/hello",
+            "one_txt/world",
+        ]
+    );
+    assert_eq!(
+        paths(Assets.one_txt().walk_breadth_first()),
+        vec!["one_txt/hello", "one_txt/world"]
+    );
+}
+
+#[test]
+fn files() {
+    let files = Assets
+        .walk()
+        .iter()
+        .filter_map(|e| e.file())
+        .map(|f| f.path().relative_path_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        files,
+        vec![
+            "one_txt/hello",
+            "one_txt/world",
+            "hello.txt",
+            "one.txt",
+            "world.txt"
+        ]
+    );
+}
+
+#[test]
+fn as_trait_object() {
+    fn count(dir: &dyn Walk<DynDir, DynFile>) -> usize {
+        dir.walk().len()
+    }
+
+    assert_eq!(count(&Assets), 6);
+    assert_eq!(count(Assets.one_txt()), 2);
+}
+
+#[cfg(feature = "sha2")]
+mod with_hashed_name {
+    use embed_it::Entry;
+
+    #[derive(embed_it::Embed)]
+    #[embed(
+        path = "$CARGO_MANIFEST_DIR/../example_dirs/assets",
+        dir(derive(Walk)),
+        file(derive(HashedName))
+    )]
+    pub struct Assets;
+
+    #[test]
+    fn hashed_names_are_not_walked() {
+        let walked = Assets
+            .walk()
+            .iter()
+            .map(|e| match e {
+                Entry::Dir(d) => d.path().relative_path_str(),
+                Entry::File(f) => f.path().relative_path_str(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(walked.len(), 6);
+        assert_eq!(walked[1], "one_txt/hello");
+        assert_eq!(walked.len(), Assets.recursive_child_count());
+    }
+}
@@ -0,0 +1,4 @@
+fn main() {
+    embed_it_build::track_dir("$CARGO_MANIFEST_DIR/public").unwrap();
+    embed_it_build::track_dir("$CARGO_MANIFEST_DIR/templates").unwrap();
+}
@@ -9,7 +9,7 @@ use actix_web::{
     },
     web, App, HttpRequest, HttpResponse, HttpServer,
 };
-use embed_it::Entry;
+use embed_it::{Blake3_256Hash, Entry};
 use hex::ToHex;
 use templates::{DirModel, EntryModel, Templates};
 use tinytemplate::TinyTemplate;
@@ -28,6 +28,10 @@ use tracing_subscriber::{filter, layer::SubscriberExt, Layer, Registry};
         derive(Zstd),
         derive(Gzip),
         derive(Brotli),
+        derive(Mime),
+        mime(sniff),
+        // do not store compressed content, which is not at least 10% smaller
+        compression(min_ratio = 1.1),
         field(factory = ETagHeaderValue, name = etag, trait_name = FileETagField, global),
     )
 )]
@@ -98,16 +102,20 @@ pub enum ChoosenEncoding {
 }
 
 impl ChoosenEncoding {
-    pub fn content<F: File + ?Sized>(&self, file: &F) -> &'static [u8] {
+    pub fn content<F: File + ?Sized>(&self, file: &F) -> Option<&'static [u8]> {
         match self {
             ChoosenEncoding::Zstd => file.zstd_content(),
             ChoosenEncoding::Brotli => file.brotli_content(),
             ChoosenEncoding::Gzip => file.gzip_content(),
-            ChoosenEncoding::Identity => file.content(),
+            ChoosenEncoding::Identity => Some(file.content()),
         }
     }
 
-    pub fn find_first(mut header: AcceptEncoding) -> Option<ChoosenEncoding> {
+    /// Finds the most preferred encoding, for which the file has the content
+    pub fn find_first<F: File + ?Sized>(
+        mut header: AcceptEncoding,
+        file: &F,
+    ) -> Option<(ChoosenEncoding, &'static [u8])> {
         header.0.sort_by(|l, r| r.quality.cmp(&l.quality));
         header
             .0
@@ -126,7 +134,7 @@ impl ChoosenEncoding {
                     Encoding::Unknown(_) => None,
                 },
             })
-            .next()
+            .find_map(|e| e.content(file).map(|content| (e, content)))
     }
 
     pub fn encoding(&self) -> ContentEncoding {
@@ -162,14 +170,15 @@ async fn public(request: HttpRequest, tail: web::Path<String>) -> HttpResponse {
 
             match entry {
                 Entry::File(f) => {
-                    let chosen_encoding = AcceptEncoding::parse(&request)
+                    let file = f.into_file();
+                    let (chosen_encoding, content) = AcceptEncoding::parse(&request)
                         .ok()
-                        .and_then(ChoosenEncoding::find_first)
-                        .unwrap_or_default();
-                    let content = chosen_encoding.content(f.into_file());
+                        .and_then(|header| ChoosenEncoding::find_first(header, file))
+                        .unwrap_or((ChoosenEncoding::Identity, file.content()));
                     let encoding = chosen_encoding.encoding();
                     HttpResponse::Ok()
                         .insert_header((CONTENT_ENCODING, encoding))
+                        .insert_header((CONTENT_TYPE, f.mime()))
                         .insert_header((ETAG, etag.header.as_str()))
                         .body(content)
                 }
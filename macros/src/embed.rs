@@ -1,18 +1,29 @@
 pub mod attributes;
 pub mod bool_like_enum;
+pub mod entry_enum;
+pub mod file_ids;
+pub mod manifest_file;
 pub mod pattern;
 pub mod regex;
+pub mod walk_table;
 
-use std::{borrow::Cow, collections::HashSet};
+use std::{
+    borrow::Cow,
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
 
 use attributes::{
+    conflicts::Conflicts,
     dir::DirTrait,
     embed::{EmbedInput, GenerationSettings},
     field::FieldTrait,
     file::FileTrait,
 };
+use bool_like_enum::BoolLikeEnum;
 use darling::FromDeriveInput;
 use embed_it_utils::entry::{Entry, EntryKind};
+use manifest_file::ManifestRecord;
 use proc_macro2::Span;
 use quote::quote;
 use syn::{
@@ -23,8 +34,12 @@ use syn::{
 use crate::{
     embedded_traits::{
         EMBEDED_TRAITS, EmbeddedTrait, MakeEmbeddedTraitImplementationError, TraitAttr,
+        find::FindTrait, walk::WalkTrait,
+    },
+    fs::{
+        EntryIdent, EntryPath, FsInfo, IgnoreFiles, ReadEntriesError, ReadIgnoreFilesError,
+        ReadOptions, StrIdent,
     },
-    fs::{EntryIdent, EntryPath, FsInfo, ReadEntriesError, StrIdent},
     utils::{anymap::AnyMap, unique_names::UniqueIdents},
 };
 
@@ -37,38 +52,110 @@ pub(crate) fn impl_embed(input: DeriveInput) -> Result<proc_macro2::TokenStream,
 
     let mut context = GenerateContext::root(&settings)?;
 
-    let impls = context
-        .build_dir(&mut Vec::new(), &mut Vec::new())
-        .map_err(|e| {
+    let mut entries = Vec::new();
+    let mut index = Vec::new();
+    let impls = context.build_dir(&mut entries, &mut index).map_err(|e| {
+        Error::new_spanned(
+            main_struct_ident,
+            format!("Unable to build root struct: {e:#?}"),
+        )
+    })?;
+
+    for mount in settings.dir.mounts() {
+        let path = mount.path();
+        if !index.iter().any(|i| i.mount && i.relative_path == path) {
+            return Err(Error::new_spanned(
+                main_struct_ident,
+                format!("The mount `{path}` has no embedded parent dir"),
+            ));
+        }
+    }
+
+    if let Some(path) = &settings.manifest {
+        let root = manifest_file::record(&context, &entries).map_err(|e| {
             Error::new_spanned(
                 main_struct_ident,
-                format!("Unable to build root struct: {e:#?}"),
+                format!("Unable to make the manifest: {e:#?}"),
             )
         })?;
-    let dir_trait_definition = settings.dir.definition(&settings);
-    let file_trait_definition = settings.file.definition(&settings);
-
+        manifest_file::write(path, &root).map_err(|e| {
+            Error::new_spanned(
+                main_struct_ident,
+                format!("Unable to write the manifest to {path:?}: {e}"),
+            )
+        })?;
+    }
+    // changes of an archive force recompilation like changes of included files
+    let archives = settings
+        .roots
+        .iter()
+        .filter_map(|root| root.archive())
+        .fold(quote! {}, |mut stream, archive| {
+            let path = archive.path().to_string_lossy();
+            stream.extend(quote! {
+                const _: &[u8] = include_bytes!(#path);
+            });
+            stream
+        });
     let field_traits_definition = settings
         .field_traits_definition()
         .map_err(|e| Error::new_spanned(main_struct_ident, e))?;
     let field_traits_implementation = context.field_traits_implementation();
 
-    let embedded_traits_definition = generate_embedded_trait_definitions(&settings);
-    let entry_implementation = settings.entry.implementation(&settings.dir, &settings.file);
-
-    let dir_field_factory_definition = generate_factory_trait_definition(&settings.dir);
-    let file_field_factory_definition = generate_factory_trait_definition(&settings.file);
+    let definitions = match &settings.definitions {
+        Some(path) => generate_definitions_import(&settings, path),
+        None => {
+            let embedded_traits_definition = generate_embedded_trait_definitions(&settings);
+            let entry_implementation = settings.entry.implementation(&settings.dir, &settings.file);
+            let dir_trait_definition = settings.dir.definition(&settings);
+            let file_trait_definition = settings.file.definition(&settings);
+            let dir_field_factory_definition = generate_factory_trait_definition(&settings.dir);
+            let file_field_factory_definition = generate_factory_trait_definition(&settings.file);
+            quote! {
+                #embedded_traits_definition
+                #entry_implementation
+                #dir_trait_definition
+                #file_trait_definition
+                #dir_field_factory_definition
+                #file_field_factory_definition
+                #field_traits_definition
+            }
+        }
+    };
+    let file_enum = settings
+        .file_enum
+        .as_ref()
+        .map(|ident| entry_enum::generate(&settings, EntryKind::File, ident, &index));
+    let dir_enum = settings
+        .dir_enum
+        .as_ref()
+        .map(|ident| entry_enum::generate(&settings, EntryKind::Dir, ident, &index));
+    let file_ids = settings
+        .file_ids
+        .as_bool()
+        .then(|| file_ids::generate(&settings, &index));
+    let walk = settings.dir.is_trait_implemented(&WalkTrait);
+    let find = settings.dir.is_trait_implemented(&FindTrait);
+    let walk_table = (walk || find).then(|| walk_table::generate(&settings, &index, walk, find));
+    let mounted_at = settings.mounted_at.as_deref().unwrap_or_default();
     let stream = quote! {
-        #embedded_traits_definition
-        #entry_implementation
-        #dir_trait_definition
-        #file_trait_definition
-        #dir_field_factory_definition
-        #file_field_factory_definition
-        #field_traits_definition
+        #definitions
 
         #field_traits_implementation
         #impls
+
+        #file_enum
+        #dir_enum
+        #file_ids
+        #walk_table
+        #archives
+
+        #[automatically_derived]
+        impl #main_struct_ident {
+            /// The path, at which the embed is mounted into another one, see `mounted_at`
+            #[doc(hidden)]
+            pub const MOUNTED_AT: &'static str = #mounted_at;
+        }
     };
     Ok(stream)
 }
@@ -95,6 +182,36 @@ fn generate_embedded_trait_definitions(settings: &GenerationSettings) -> proc_ma
     stream
 }
 
+/// Imports the traits and the entry structs of another embed instead of defining them
+fn generate_definitions_import(
+    settings: &GenerationSettings,
+    path: &syn::Path,
+) -> proc_macro2::TokenStream {
+    let mut names = vec![
+        settings.dir.trait_ident(),
+        settings.file.trait_ident(),
+        settings.dir.field_factory_trait_ident(),
+        settings.file.field_factory_trait_ident(),
+        settings.entry.dir_trait_ident(),
+        settings.entry.file_trait_ident(),
+        settings.entry.dir_struct_ident(),
+        settings.entry.file_struct_ident(),
+    ];
+    names.extend(
+        settings
+            .dir
+            .fields()
+            .iter()
+            .chain(settings.file.fields().iter())
+            .map(|f| f.trait_ident()),
+    );
+
+    quote! {
+        #[allow(unused_imports)]
+        use #path::{#(#names),*};
+    }
+}
+
 fn generate_factory_trait_definition(attr: &impl TraitAttr) -> proc_macro2::TokenStream {
     let trait_ident = attr.trait_ident();
     let ident = attr.field_factory_trait_ident();
@@ -118,8 +235,26 @@ pub struct IndexTokens {
     pub relative_path: String,
     pub struct_path: syn::Path,
     pub kind: EntryKind,
+
+    /// The relative path is an additional name of the entry, see [`IndexAliases`]
+    pub alias: bool,
+
+    /// The entry is a mounted embed, its descendants are found by its own `get`
+    pub mount: bool,
+}
+
+/// A separately derived embed, which is mounted as a direct child of the dir
+pub struct MountTokens {
+    /// The path of the re-exported struct relative to the dir module
+    pub struct_path: syn::Path,
+    pub field: StrIdent,
 }
 
+/// Additional names of an entry in the indexes of its parents.
+/// Embedded traits put them into [`GenerateContext::items`]
+#[derive(Debug, Default)]
+pub struct IndexAliases(pub Vec<String>);
+
 pub fn nested_module_path(nesting: usize) -> Punctuated<PathSegment, PathSep> {
     let mut new_segments = Punctuated::new();
     for _ in 0..nesting {
@@ -185,6 +320,15 @@ pub struct GenerateContext<'a> {
 
     /// The parents of the entry from the root to the direct
     pub parents: Vec<ParentTokens>,
+
+    /// Canonical paths of the parent dirs (and of the entry itself, if it is a dir) in all roots to detect symlink cycles
+    ancestors: Vec<PathBuf>,
+
+    /// Ignore files of the parent dirs (and of the entry itself, if it is a dir)
+    ignore_files: IgnoreFiles,
+
+    /// Embeds mounted into the dir, see [`attributes::mount::Mount`]
+    pub mounts: Vec<MountTokens>,
 }
 
 /// Parent of the entry
@@ -201,13 +345,13 @@ impl<'a> GenerateContext<'a> {
 
     /// Creates the root-level context
     fn root(settings: &'a GenerationSettings) -> Result<Self, syn::Error> {
-        let entry = FsInfo::root_entry(&settings.root, settings.main_struct_ident.to_owned())
+        let entry = FsInfo::root_entry(&settings.roots, settings.main_struct_ident.to_owned())
             .map_err(|e| {
                 Error::new_spanned(
                     &settings.main_struct_ident,
                     format!(
                         "Unable to read directory '{:?}' information: {e:?}",
-                        settings.root
+                        settings.roots
                     ),
                 )
             })?;
@@ -220,6 +364,9 @@ impl<'a> GenerateContext<'a> {
             settings,
             items: Default::default(),
             parents: Default::default(),
+            ancestors: Default::default(),
+            ignore_files: Default::default(),
+            mounts: Default::default(),
         })
     }
 
@@ -252,6 +399,9 @@ impl<'a> GenerateContext<'a> {
             settings: self.settings,
             items: Default::default(),
             parents,
+            ancestors: self.ancestors.clone(),
+            ignore_files: self.ignore_files.clone(),
+            mounts: Default::default(),
         }
     }
 
@@ -294,6 +444,12 @@ impl<'a> GenerateContext<'a> {
                 .map_err(BuildStreamError::Dir)?,
         };
 
+        if self.settings.manifest.is_some() {
+            let record =
+                manifest_file::record(&self, &entries).map_err(BuildStreamError::Manifest)?;
+            *self.items.get_or_default::<ManifestRecord>() = ManifestRecord(record);
+        }
+
         let path = self.entry.as_ref().value().path();
         let struct_ident = path.ident().struct_like();
         let mod_ident = path.ident().module_like();
@@ -309,20 +465,32 @@ impl<'a> GenerateContext<'a> {
             }
         };
 
+        // the entry goes before its children, so the index is in depth-first order
         let index_relative_path = &self.entry.as_ref().value().path().file_name;
-        parent_index.extend(index.into_iter().map(|mut i| {
-            let prev_path = i.struct_path;
-            i.struct_path = parse_quote!(#mod_ident::#prev_path);
-            i.relative_path = format!("{index_relative_path}/{}", i.relative_path);
-            i
-        }));
-
         let struct_path: syn::Path = parse_quote!(#mod_ident::#struct_ident);
         parent_index.push(IndexTokens {
             relative_path: index_relative_path.clone(),
             struct_path: struct_path.clone(),
             kind: self.entry.kind(),
+            alias: false,
+            mount: false,
         });
+        if let Some(aliases) = self.items.get::<IndexAliases>() {
+            parent_index.extend(aliases.0.iter().map(|alias| IndexTokens {
+                relative_path: alias.clone(),
+                struct_path: struct_path.clone(),
+                kind: self.entry.kind(),
+                alias: true,
+                mount: false,
+            }));
+        }
+
+        parent_index.extend(index.into_iter().map(|mut i| {
+            let prev_path = i.struct_path;
+            i.struct_path = parse_quote!(#mod_ident::#prev_path);
+            i.relative_path = format!("{index_relative_path}/{}", i.relative_path);
+            i
+        }));
 
         parent_entries.push(EntryTokens {
             struct_path: struct_path.clone(),
@@ -355,17 +523,111 @@ impl<'a> GenerateContext<'a> {
         entries: &mut Vec<EntryTokens>,
         index: &mut Vec<IndexTokens>,
     ) -> Result<proc_macro2::TokenStream, BuildDirError> {
-        let children = FsInfo::read(
-            self.entry.as_ref().value().path().origin_path(),
-            &self.settings.root,
-            self.settings.with_extension,
+        let layers = self.entry.as_ref().value().layers().to_vec();
+        // dirs of archives are virtual, they have no symlinks and ignore files
+        for layer in layers
+            .iter()
+            .filter(|l| self.settings.roots[l.root].archive().is_none())
+        {
+            let real_path =
+                std::fs::canonicalize(&layer.origin).map_err(BuildDirError::Canonicalize)?;
+            self.ancestors.push(real_path);
+            if self.settings.respect_ignore_files.as_bool() {
+                self.ignore_files = self
+                    .ignore_files
+                    .with_dir(&layer.origin)
+                    .map_err(BuildDirError::ReadIgnoreFiles)?;
+            }
+        }
+
+        let settings = self.settings;
+        let relative = self.entry_path().relative.clone();
+        // mounts get their idents first, so a shadowed entry doesn't take the name
+        let mounts = settings
+            .dir
+            .mounts_of(&relative)
+            .map(|mount| {
+                let name = if settings.with_extension.as_bool() {
+                    mount.name()
+                } else {
+                    Path::new(mount.name())
+                        .file_stem()
+                        .and_then(|s| s.to_str())
+                        .unwrap_or(mount.name())
+                };
+                let ident = EntryIdent::create(name, &mut self.unique_idents)
+                    .expect("a mount name is never empty");
+                (mount, ident)
+            })
+            .collect::<Vec<_>>();
+
+        let options = ReadOptions {
+            roots: &settings.roots,
+            with_extension: settings.with_extension,
+            symlinks: settings.symlinks,
+            conflicts: settings.conflicts,
+        };
+        let mut children = FsInfo::read(
+            &layers,
+            options,
+            &self.ancestors,
+            &self.ignore_files,
             &mut self.unique_idents,
         )
         .map_err(BuildDirError::ReadEntries)?;
+        for (mount, _) in &mounts {
+            let shadowed = children
+                .iter()
+                .position(|c| c.as_ref().value().path().file_name == mount.name());
+            if let Some(idx) = shadowed {
+                if settings.conflicts == Conflicts::Error {
+                    return Err(BuildDirError::MountConflict(mount.path()));
+                }
+                children.remove(idx);
+            }
+        }
+
         let mut modules = proc_macro2::TokenStream::new();
         for entry in children {
             let child = self.child(entry);
-            modules.extend(child.build(entries, index));
+            modules.extend(
+                child
+                    .build(entries, index)
+                    .map_err(|e| BuildDirError::Child(Box::new(e)))?,
+            );
+        }
+
+        for (mount, ident) in mounts {
+            let mod_ident = ident.module_like();
+            let struct_ident = ident.struct_like();
+            let embed = fix_path(mount.embed(), self.level + 1);
+            let path = mount.path();
+            let message = format!(
+                "the embed mounted at `{path}` must be derived with `mounted_at = \"{path}\"`"
+            )
+            .replace('{', "{{")
+            .replace('}', "}}");
+            modules.extend(quote! {
+                pub mod #mod_ident {
+                    pub use #embed as #struct_ident;
+
+                    // paths of the mounted entries start with the mount point
+                    const _: () = assert!(::embed_it::str_eq(#struct_ident::MOUNTED_AT, #path), #message);
+                }
+            });
+
+            let struct_path: syn::Path = parse_quote!(#mod_ident::#struct_ident);
+            index.push(IndexTokens {
+                relative_path: mount.name().to_owned(),
+                struct_path: struct_path.clone(),
+                kind: EntryKind::Dir,
+                alias: false,
+                mount: true,
+            });
+            self.mounts.push(MountTokens {
+                struct_path,
+                field: mod_ident.clone(),
+            });
         }
 
         let impl_stream = self
@@ -432,11 +694,16 @@ impl<'a> GenerateContext<'a> {
 pub enum BuildStreamError {
     Dir(#[allow(dead_code)] BuildDirError),
     File(#[allow(dead_code)] BuildFileError),
+    Manifest(#[allow(dead_code)] MakeEmbeddedTraitImplementationError),
 }
 
 #[derive(Debug)]
 pub enum BuildDirError {
     ReadEntries(#[allow(dead_code)] ReadEntriesError),
+    Canonicalize(#[allow(dead_code)] std::io::Error),
+    ReadIgnoreFiles(#[allow(dead_code)] ReadIgnoreFilesError),
+    MountConflict(#[allow(dead_code)] String),
+    Child(#[allow(dead_code)] Box<BuildStreamError>),
     MakeEmbeddedTraitImplementation(#[allow(dead_code)] MakeEmbeddedTraitImplementationError),
 }
 
@@ -648,12 +915,23 @@ mod tests {
         let path_str = current_dir.to_str().unwrap();
         let input = EmbedInput {
             ident: Ident::new("sss", Span::call_site()),
-            path: path_str.to_owned(),
+            path: vec![path_str.to_owned()],
+            conflicts: Default::default(),
             with_extension: Default::default(),
             support_alt_separator: Default::default(),
+            dev_mode: Default::default(),
+            symlinks: Default::default(),
+            respect_ignore_files: Default::default(),
+            index: Default::default(),
             dir: Default::default(),
             file: Default::default(),
             entry: Default::default(),
+            file_enum: Default::default(),
+            dir_enum: Default::default(),
+            file_ids: Default::default(),
+            manifest: Default::default(),
+            definitions: Default::default(),
+            mounted_at: Default::default(),
         };
         let err = GenerationSettings::try_from(input).unwrap_err();
         let err_str = format!("{err:?}");
@@ -850,6 +1128,835 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dev_mode() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("hello.txt"), b"hello");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = #path,
+                dev_mode = "debug_assertions",
+                file(derive(StrContent)),
+            )]
+            pub struct Assets;
+        });
+        impl_embed(input).print_to_std_out();
+    }
+
+    #[test]
+    fn dev_mode_shares_disk_content() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("hello.txt"), b"hello");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = #path,
+                dev_mode = "debug_assertions",
+                file(derive(Content), derive(StrContent)),
+            )]
+            pub struct Assets;
+        });
+
+        let generated = impl_embed(input).unwrap().to_string();
+        assert_eq!(generated.matches("DiskContent :: new").count(), 1);
+        // a file in dev mode must not be a dependency, otherwise its changes force recompilation
+        assert!(!generated.contains("const _ : & [u8] = include_bytes !"));
+    }
+
+    #[test]
+    fn sorted_index() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("hello.txt"), b"hello");
+        create_file(current_dir.join("world.txt"), b"world");
+
+        let subdir = current_dir.join("subdir");
+        create_dir_all(&subdir);
+        create_file(subdir.join("hello.txt"), b"hello");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = #path,
+                support_alt_separator,
+                index(kind = "sorted"),
+            )]
+            pub struct Assets;
+        });
+        impl_embed(input).print_to_std_out();
+    }
+
+    #[test]
+    fn mime() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("index.html"), b"<html></html>");
+        create_file(current_dir.join("index.tpl"), b"<html></html>");
+        create_file(current_dir.join("image"), b"\x89PNG\r\n\x1a\n");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = #path,
+                file(
+                    derive(Mime),
+                    mime(sniff, map(pattern = "*.tpl", value = "text/html")),
+                ),
+            )]
+            pub struct Assets;
+        });
+        impl_embed(input).print_to_std_out();
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn sri() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("app.js"), b"console.log('hello')");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = #path,
+                file(derive(Sha2_384), derive(Sri(Sha2_384))),
+            )]
+            pub struct Assets;
+        });
+        impl_embed(input).print_to_std_out();
+    }
+
+    #[test]
+    fn index_lookup_options() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("Hello.txt"), b"hello");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = #path,
+                index(kind = "sorted", case_insensitive, normalize_path, percent_decode),
+                dir(derive(Index)),
+            )]
+            pub struct Assets;
+        });
+        impl_embed(input).print_to_std_out();
+    }
+
+    #[test]
+    fn entry_enums() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("hello.txt"), b"hello");
+        create_dir_all(current_dir.join("one_txt"));
+        create_file(current_dir.join("one_txt").join("hello"), b"hello");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = #path,
+                file_enum,
+                dir_enum = "AssetDir",
+            )]
+            pub struct Assets;
+        });
+        impl_embed(input).print_to_std_out();
+    }
+
+    #[test]
+    fn file_ids() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("b.txt"), b"b");
+        create_dir_all(current_dir.join("a"));
+        create_file(current_dir.join("a").join("c.txt"), b"c");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(path = #path, file_ids)]
+            pub struct Assets;
+        });
+        impl_embed(input).print_to_std_out();
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn manifest() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("hello.txt"), b"hello");
+        create_dir_all(current_dir.join("one_txt"));
+        create_file(current_dir.join("one_txt").join("world"), b"world");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = #path,
+                dir(derive(Manifest), derive(Sha2_256)),
+                file(derive(Manifest), derive(Sha2_256)),
+            )]
+            pub struct Assets;
+        });
+        impl_embed(input).print_to_std_out();
+    }
+
+    #[test]
+    fn manifest_requires_files() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("hello.txt"), b"hello");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(path = #path, dir(derive(Manifest)))]
+            pub struct Assets;
+        });
+
+        let err = format!("{:?}", impl_embed(input).unwrap_err());
+        assert!(
+            err.contains("`Manifest` for dirs requires `Manifest` to be derived for files"),
+            "Unable to find the reason in a error string: '{err}'"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn manifest_file() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        let assets = current_dir.join("assets");
+        create_dir_all(assets.join("one_txt"));
+        create_file(assets.join("hello.txt"), b"hello");
+        create_file(assets.join("one_txt").join("world"), b"world");
+
+        let path = assets.to_str().unwrap();
+        let manifest = current_dir.join("out").join("assets.json");
+        let manifest_str = manifest.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = #path,
+                manifest = #manifest_str,
+                dir(derive_default_traits = false, derive(Path)),
+                file(derive_default_traits = false, derive(Path), derive(Sha2_256)),
+            )]
+            pub struct Assets;
+        });
+        impl_embed(input).unwrap();
+
+        let actual: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&manifest).unwrap()).unwrap();
+        assert_eq!(
+            actual,
+            serde_json::json!({
+                "kind": "dir",
+                "path": "",
+                "struct": "Assets",
+                "field": "assets",
+                "traits": ["EntryPath"],
+                "hashes": {},
+                "entries": [
+                    {
+                        "kind": "dir",
+                        "path": "one_txt",
+                        "struct": "OneTxt",
+                        "field": "one_txt",
+                        "traits": ["EntryPath"],
+                        "hashes": {},
+                        "entries": [
+                            {
+                                "kind": "file",
+                                "path": "one_txt/world",
+                                "struct": "World",
+                                "field": "world",
+                                "size": 5,
+                                "traits": ["EntryPath", "Sha2_256Hash"],
+                                "hashes": {
+                                    "sha2_256": "486ea46224d1bb4fb680f34f7c9ad96a8f24ec88be73ea8e5a6c65260e9cb8a7"
+                                },
+                            },
+                        ],
+                    },
+                    {
+                        "kind": "file",
+                        "path": "hello.txt",
+                        "struct": "Hello",
+                        "field": "hello",
+                        "size": 5,
+                        "traits": ["EntryPath", "Sha2_256Hash"],
+                        "hashes": {
+                            "sha2_256": "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+                        },
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn symlink_cycle() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_dir_all(current_dir.join("a"));
+        create_dir_all(current_dir.join("b"));
+        create_file(current_dir.join("a").join("hello.txt"), b"hello");
+        std::os::unix::fs::symlink("../b", current_dir.join("a").join("to_b")).unwrap();
+        std::os::unix::fs::symlink("../a", current_dir.join("b").join("to_a")).unwrap();
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(path = #path)]
+            pub struct Assets;
+        });
+
+        let err = format!("{:?}", impl_embed(input).unwrap_err());
+        assert!(
+            err.contains("SymlinkCycle"),
+            "Unable to find the cycle in a error string: '{err}'"
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn symlinks_error() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("hello.txt"), b"hello");
+        std::os::unix::fs::symlink("hello.txt", current_dir.join("link.txt")).unwrap();
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(path = #path, symlinks = "error")]
+            pub struct Assets;
+        });
+
+        let err = format!("{:?}", impl_embed(input).unwrap_err());
+        assert!(
+            err.contains("Symlink(") && err.contains("link.txt"),
+            "Unable to find the symlink in a error string: '{err}'"
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn symlinks_preserve() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("hello.txt"), b"hello");
+        std::os::unix::fs::symlink("missing.txt", current_dir.join("dangling.txt")).unwrap();
+        std::os::unix::fs::symlink(".", current_dir.join("itself")).unwrap();
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(path = #path, symlinks = "preserve", file(derive(LinkTarget)))]
+            pub struct Assets;
+        });
+        impl_embed(input).print_to_std_out();
+    }
+
+    #[test]
+    #[cfg(feature = "ignore")]
+    fn respect_ignore_files() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join(".gitignore"), b"*.swp\n");
+        create_dir_all(current_dir.join("nested"));
+        create_file(current_dir.join("nested").join("ignored.swp"), b"swp");
+        create_file(current_dir.join("nested").join("included.txt"), b"txt");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(path = #path, respect_ignore_files)]
+            pub struct Assets;
+        });
+
+        let generated = impl_embed(input).unwrap().to_string();
+        assert!(generated.contains("pub struct Included"));
+        assert!(!generated.contains("pub struct Ignored"));
+    }
+
+    #[test]
+    #[cfg(not(feature = "ignore"))]
+    fn respect_ignore_files_requires_feature() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(path = #path, respect_ignore_files)]
+            pub struct Assets;
+        });
+
+        let err = impl_embed(input).unwrap_err().to_string();
+        assert_eq!(err, "`respect_ignore_files` requires the `ignore` feature");
+    }
+
+    #[test]
+    fn overlay_conflicts_error() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_dir_all(current_dir.join("base").join("dir"));
+        create_dir_all(current_dir.join("custom").join("dir"));
+        create_file(current_dir.join("base").join("dir").join("a.txt"), b"a");
+        create_file(current_dir.join("custom").join("dir").join("b.txt"), b"b");
+
+        let base = current_dir.join("base");
+        let base = base.to_str().unwrap();
+        let custom = current_dir.join("custom");
+        let custom = custom.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(path = #base, path = #custom, conflicts = "error")]
+            pub struct Assets;
+        });
+
+        // merged dirs are not conflicts
+        let generated = impl_embed(input.clone()).unwrap().to_string();
+        assert!(generated.contains("pub struct A"));
+        assert!(generated.contains("pub struct B"));
+
+        create_file(
+            current_dir.join("custom").join("dir").join("a.txt"),
+            b"shadow",
+        );
+        let err = format!("{:?}", impl_embed(input).unwrap_err());
+        assert!(
+            err.contains("Conflict(") && err.contains("a.txt"),
+            "Unable to find the conflict in a error string: '{err}'"
+        );
+    }
+
+    #[test]
+    fn mount() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_dir_all(current_dir.join("dir").join("sub"));
+        create_file(current_dir.join("dir").join("sub").join("a.txt"), b"a");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = #path,
+                index(kind = "sorted"),
+                dir(mount(path = "dir/sub", embed = other::Other)),
+            )]
+            pub struct Assets;
+        });
+
+        let generated = impl_embed(input.clone()).unwrap().to_string();
+        // the mount shadows the dir and its path is fixed for the nesting
+        assert!(!generated.contains("pub struct A "));
+        assert!(generated.contains("pub use super :: super :: other :: Other as Sub ;"));
+        assert!(generated.contains("strip_mount (path , \"dir/sub\")"));
+        assert!(generated.contains("str_eq (Sub :: MOUNTED_AT , \"dir/sub\")"));
+        assert!(!generated.contains("pub const fn get"));
+
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = #path,
+                conflicts = "error",
+                dir(mount(path = "dir/sub", embed = other::Other)),
+            )]
+            pub struct Assets;
+        });
+        let err = format!("{:?}", impl_embed(input).unwrap_err());
+        assert!(
+            err.contains("MountConflict(") && err.contains("dir/sub"),
+            "Unable to find the conflict in a error string: '{err}'"
+        );
+
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(path = #path, dir(mount(path = "missing/sub", embed = other::Other)))]
+            pub struct Assets;
+        });
+        let err = impl_embed(input).unwrap_err().to_string();
+        assert_eq!(err, "The mount `missing/sub` has no embedded parent dir");
+    }
+
+    #[test]
+    fn mounted_at() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("a.txt"), b"a");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(path = #path, with_extension, mounted_at = "/dir/sub.d/")]
+            pub struct Other;
+        });
+
+        let generated = impl_embed(input).unwrap().to_string();
+        let root = quote! { ::embed_it::EmbeddedPath::new("dir/sub.d", "sub.d", "sub") };
+        let file = quote! { ::embed_it::EmbeddedPath::new("dir/sub.d/a.txt", "a.txt", "a") };
+        assert!(generated.contains(&root.to_string()));
+        assert!(generated.contains(&file.to_string()));
+        assert!(generated.contains("pub const MOUNTED_AT : & 'static str = \"dir/sub.d\" ;"));
+
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(path = #path, mounted_at = "dir/../sub")]
+            pub struct Other;
+        });
+        let err = impl_embed(input).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "`mounted_at` `dir/../sub` must be a relative path without `.` and `..` segments"
+        );
+    }
+
+    #[test]
+    fn mount_walk() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_dir_all(current_dir.join("dir"));
+        create_file(current_dir.join("dir").join("a.txt"), b"a");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = #path,
+                dir(derive(Walk), mount(path = "dir/sub", embed = other::Other)),
+            )]
+            pub struct Assets;
+        });
+
+        let generated = impl_embed(input).unwrap().to_string();
+        // the table of the mounted embed follows the mount point
+        assert!(
+            generated.contains(
+                "concat :: < _ , { 3usize + dir :: sub :: Sub :: WALK_ENTRIES . len () } >"
+            )
+        );
+        assert!(generated.contains("(dir :: sub :: Sub :: WALK_DEPTHS , 2u16) ,"));
+    }
+
+    #[test]
+    fn mount_unsupported() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("a.txt"), b"a");
+
+        let path = current_dir.to_str().unwrap();
+        for (option, expected) in [
+            (
+                quote! { dir(derive(Find), mount(path = "sub", embed = other::Other)) },
+                "dir(derive(Find))",
+            ),
+            (
+                quote! { file_ids, dir(mount(path = "sub", embed = other::Other)) },
+                "file_ids",
+            ),
+            (
+                quote! { file_enum, dir(mount(path = "sub", embed = other::Other)) },
+                "file_enum",
+            ),
+        ] {
+            let input = derive_input(quote! {
+                #[derive(embed_it::Embed)]
+                #[embed(path = #path, #option)]
+                pub struct Assets;
+            });
+            let err = impl_embed(input).unwrap_err().to_string();
+            assert!(
+                err.starts_with(&format!("`{expected}` is not supported with `mount`")),
+                "{err}"
+            );
+        }
+    }
+
+    #[test]
+    fn definitions() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("a.txt"), b"a");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(path = #path, definitions = super::assets)]
+            pub struct Assets;
+        });
+
+        let generated = impl_embed(input).unwrap().to_string();
+        assert!(generated.contains(
+            "use super :: assets :: { Dir , File , DirFieldFactory , FileFieldFactory , EntryDir , EntryFile , DynDir , DynFile } ;"
+        ));
+        assert!(!generated.contains("pub trait"));
+    }
+
+    #[test]
+    fn unknown_archive_format() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        let file = current_dir.join("assets.rar");
+        create_file(&file, b"rar");
+
+        let path = file.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(path = #path)]
+            pub struct Assets;
+        });
+
+        let err = impl_embed(input).unwrap_err().to_string();
+        assert!(
+            err.contains("is not a tar or zip archive"),
+            "Unable to find the format in a error string: '{err}'"
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "zip"))]
+    fn archive_requires_feature() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        let file = current_dir.join("assets.zip");
+        create_file(&file, b"zip");
+
+        let path = file.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(path = #path)]
+            pub struct Assets;
+        });
+
+        let err = impl_embed(input).unwrap_err().to_string();
+        assert!(
+            err.ends_with("is a zip archive, which requires the `zip` feature"),
+            "Unable to find the feature in a error string: '{err}'"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tar")]
+    fn archive() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        let file = current_dir.join("assets.tar");
+
+        let mut builder = tar::Builder::new(Vec::new());
+        let mut header = tar::Header::new_gnu();
+        header.set_size(5);
+        header.set_mtime(1);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, "./dir/hello.txt", &b"hello"[..])
+            .unwrap();
+        create_file(&file, &builder.into_inner().unwrap());
+
+        let path = file.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(path = #path, file(derive(Meta)), dev_mode = "debug_assertions")]
+            pub struct Assets;
+        });
+
+        let generated = impl_embed(input).unwrap().to_string();
+        assert!(generated.contains("pub struct Dir"));
+        assert!(generated.contains("pub struct Hello"));
+        assert!(generated.contains("b\"hello\""));
+        // an archived file has nothing to read at runtime
+        assert!(!generated.contains("DiskContent"));
+    }
+
+    #[test]
+    fn no_path() {
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(with_extension)]
+            pub struct Assets;
+        });
+
+        let err = impl_embed(input).unwrap_err().to_string();
+        assert_eq!(err, "at least one `path` is required");
+    }
+
+    #[test]
+    fn index_case_insensitive_collision() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("hello.txt"), b"hello");
+        create_file(current_dir.join("HELLO.txt"), b"hello");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = #path,
+                with_extension,
+                index(case_insensitive),
+                dir(derive(Index)),
+            )]
+            pub struct Assets;
+        });
+
+        let err = format!("{:?}", impl_embed(input).unwrap_err());
+        assert!(
+            err.contains("`HELLO.txt` and `hello.txt` collide"),
+            "Unable to find the collision in a error string: '{err}'"
+        );
+    }
+
+    #[test]
+    fn walk() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("hello.txt"), b"hello");
+        let subdir = current_dir.join("subdir");
+        create_dir_all(&subdir);
+        create_file(subdir.join("world.txt"), b"world");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = #path,
+                dir(derive(Walk), derive(Find)),
+            )]
+            pub struct Assets;
+        });
+        impl_embed(input).print_to_std_out();
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn hashed_name() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("app.js"), b"console.log('hello')");
+        let subdir = current_dir.join("css");
+        create_dir_all(&subdir);
+        create_file(subdir.join("style.css"), b"body {}");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = #path,
+                index(kind = "sorted"),
+                dir(derive(Index)),
+                file(derive(HashedName), hashed_name(len = 6)),
+            )]
+            pub struct Assets;
+        });
+        impl_embed(input).print_to_std_out();
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn hashed_name_collision() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("app.js"), b"console.log('hello')");
+
+        let path = current_dir.to_str().unwrap();
+        let derive = |kind: &str| {
+            derive_input(quote! {
+                #[derive(embed_it::Embed)]
+                #[embed(
+                    path = #path,
+                    index(kind = #kind),
+                    file(derive(HashedName), hashed_name(len = 6)),
+                )]
+                pub struct Assets;
+            })
+        };
+        let generated = impl_embed(derive("hash_map")).unwrap().to_string();
+        let hashed_path = generated
+            .split("const VALUE : & str = \"")
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .expect("the hashed path is generated")
+            .to_owned();
+
+        // a real file takes the hashed name of another one
+        create_file(current_dir.join(&hashed_path), b"console.log('other')");
+        for kind in ["hash_map", "sorted"] {
+            let err = format!("{:?}", impl_embed(derive(kind)).unwrap_err());
+            assert!(
+                err.contains(&format!("the hashed name `{hashed_path}` collides")),
+                "Unable to find the collision in a error string: '{err}'"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "zstd-decompress")]
+    fn store_compressed() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        create_file(current_dir.join("hello.txt"), b"hello");
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(
+                path = #path,
+                dev_mode = "debug_assertions",
+                file(
+                    derive(StrContent),
+                    derive(Zstd),
+                    compression(store_compressed = "zstd"),
+                ),
+            )]
+            pub struct Assets;
+        });
+        impl_embed(input).print_to_std_out();
+    }
+
+    #[test]
+    #[cfg(feature = "zstd-decompress")]
+    fn store_compressed_tracks_file() {
+        let current_dir = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&current_dir);
+        let file = current_dir.join("hello.txt");
+        create_file(&file, "hello".repeat(100).as_bytes());
+
+        let path = current_dir.to_str().unwrap();
+        let input = derive_input(quote! {
+            #[derive(embed_it::Embed)]
+            #[embed(path = #path, file(compression(store_compressed = "zstd")))]
+            pub struct Assets;
+        });
+
+        let generated = impl_embed(input).unwrap().to_string();
+        assert!(generated.contains("pub fn stored_content"));
+        let origin = file.canonicalize().unwrap();
+        let origin = origin.to_str().unwrap();
+        let tracking = quote! { const _: &[u8] = include_bytes!(#origin); }.to_string();
+        assert!(
+            generated.contains(&tracking),
+            "Unable to find the tracking of the file in '{generated}'"
+        );
+    }
+
     #[test]
     fn include_exclude() {
         let current_dir = tests_dir().join(fn_name!());
@@ -1,10 +1,21 @@
+pub mod compression;
+pub mod conflicts;
 pub mod derive_default_traits;
+pub mod dev_mode;
 pub mod dir;
 pub mod embed;
 pub mod entry;
+pub mod entry_enum;
 pub mod field;
 pub mod file;
+pub mod file_ids;
 pub mod global_field;
+pub mod hashed_name;
+pub mod index;
+pub mod mime;
+pub mod mount;
 pub mod path_match;
+pub mod respect_ignore_files;
 pub mod support_alt_separator;
+pub mod symlinks;
 pub mod with_extension;
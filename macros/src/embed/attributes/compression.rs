@@ -0,0 +1,404 @@
+use std::ops::RangeInclusive;
+
+use darling::FromMeta;
+
+use crate::embed::bool_like_enum::BoolLikeEnum;
+
+#[derive(Debug, Default, Clone, FromMeta)]
+pub struct CompressionAttr {
+    /// Parameters of the `Zstd` trait
+    #[darling(default)]
+    pub zstd: ZstdAttr,
+
+    /// Parameters of the `Gzip` trait
+    #[darling(default)]
+    pub gzip: GzipAttr,
+
+    /// Parameters of the `Brotli` trait
+    #[darling(default)]
+    pub brotli: BrotliAttr,
+
+    /// If set, the compressed content is stored only if `original size / compressed size >= min_ratio`
+    /// and compression traits return `Option`. Default: always stored
+    #[darling(default)]
+    pub min_ratio: Option<f64>,
+
+    /// If set, only the content compressed with the algorithm is embedded and `Content` decompresses it
+    /// on the first access. Default: the original content is embedded
+    #[darling(default)]
+    pub store_compressed: Option<CompressionKind>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromMeta)]
+pub enum CompressionKind {
+    #[darling(rename = "zstd")]
+    Zstd,
+
+    #[darling(rename = "gzip")]
+    Gzip,
+
+    #[darling(rename = "brotli")]
+    Brotli,
+}
+
+impl CompressionKind {
+    /// The value of the attribute
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::Zstd => "zstd",
+            Self::Gzip => "gzip",
+            Self::Brotli => "brotli",
+        }
+    }
+
+    /// The feature, which is required to decompress the content at runtime
+    pub fn feature(self) -> &'static str {
+        match self {
+            Self::Zstd => "zstd-decompress",
+            Self::Gzip => "gzip-decompress",
+            Self::Brotli => "brotli-decompress",
+        }
+    }
+
+    fn is_enabled(self) -> bool {
+        match self {
+            Self::Zstd => cfg!(feature = "zstd-decompress"),
+            Self::Gzip => cfg!(feature = "gzip-decompress"),
+            Self::Brotli => cfg!(feature = "brotli-decompress"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, FromMeta)]
+pub struct ZstdAttr {
+    /// Compression level. Default: 22
+    #[darling(default)]
+    pub level: Option<i32>,
+
+    /// Enables long distance matching. Default: no
+    #[darling(default)]
+    pub long_distance_matching: LongDistanceMatching,
+
+    /// Maximum back-reference distance as a power of 2, up to 27,
+    /// because decoders reject larger windows by default. Default: chosen by the level
+    #[darling(default)]
+    pub window_log: Option<u32>,
+}
+
+#[derive(Debug, Default, Clone, FromMeta)]
+pub struct GzipAttr {
+    /// Compression level. Default: 9
+    #[darling(default)]
+    pub level: Option<u32>,
+}
+
+#[derive(Debug, Default, Clone, FromMeta)]
+pub struct BrotliAttr {
+    /// Compression quality. Default: 11
+    #[darling(default)]
+    pub quality: Option<u32>,
+
+    /// LZ77 window size as a power of 2. Default: 22
+    #[darling(default)]
+    pub window: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompressionSettings {
+    pub zstd: ZstdSettings,
+    pub gzip: GzipSettings,
+    pub brotli: BrotliSettings,
+    pub min_ratio: Option<f64>,
+    pub store_compressed: Option<CompressionKind>,
+}
+
+impl CompressionSettings {
+    /// Should the compressed content be stored
+    pub fn is_worth(&self, original_len: usize, compressed_len: usize) -> bool {
+        match self.min_ratio {
+            Some(min_ratio) => {
+                compressed_len < original_len
+                    && original_len as f64 >= compressed_len as f64 * min_ratio
+            }
+            None => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZstdSettings {
+    pub level: i32,
+    pub long_distance_matching: LongDistanceMatching,
+    pub window_log: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GzipSettings {
+    pub level: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrotliSettings {
+    pub quality: u32,
+    pub window: u32,
+}
+
+impl Default for CompressionSettings {
+    fn default() -> Self {
+        Self {
+            zstd: ZstdSettings {
+                level: 22,
+                long_distance_matching: LongDistanceMatching::No,
+                window_log: None,
+            },
+            gzip: GzipSettings { level: 9 },
+            brotli: BrotliSettings {
+                quality: 11,
+                window: 22,
+            },
+            min_ratio: None,
+            store_compressed: None,
+        }
+    }
+}
+
+#[derive(Debug, derive_more::Display)]
+pub enum ParseCompressionAttrError {
+    #[display("`{name}` must be in range {}..={}, but it is {value}", range.start(), range.end())]
+    OutOfRange {
+        name: &'static str,
+        value: i64,
+        range: RangeInclusive<i64>,
+    },
+
+    #[display("`min_ratio` must be a finite number not less than 1, but it is {_0}")]
+    MinRatio(f64),
+
+    #[display("`store_compressed = \"{}\"` requires the `{}` feature", _0.id(), _0.feature())]
+    StoreCompressedFeatureDisabled(CompressionKind),
+}
+
+fn check<T: Into<i64> + Copy>(
+    name: &'static str,
+    value: Option<T>,
+    range: RangeInclusive<i64>,
+) -> Result<Option<T>, ParseCompressionAttrError> {
+    match value {
+        Some(v) if !range.contains(&v.into()) => Err(ParseCompressionAttrError::OutOfRange {
+            name,
+            value: v.into(),
+            range,
+        }),
+        _ => Ok(value),
+    }
+}
+
+impl TryFrom<CompressionAttr> for CompressionSettings {
+    type Error = ParseCompressionAttrError;
+
+    fn try_from(value: CompressionAttr) -> Result<Self, Self::Error> {
+        let CompressionAttr {
+            zstd,
+            gzip,
+            brotli,
+            min_ratio,
+            store_compressed,
+        } = value;
+        if let Some(min_ratio) = min_ratio {
+            if !min_ratio.is_finite() || min_ratio < 1.0 {
+                return Err(ParseCompressionAttrError::MinRatio(min_ratio));
+            }
+        }
+
+        if let Some(store_compressed) = store_compressed {
+            if !store_compressed.is_enabled() {
+                return Err(ParseCompressionAttrError::StoreCompressedFeatureDisabled(
+                    store_compressed,
+                ));
+            }
+        }
+
+        let default = Self::default();
+        Ok(Self {
+            zstd: ZstdSettings {
+                level: check("zstd.level", zstd.level, 1..=22)?.unwrap_or(default.zstd.level),
+                long_distance_matching: zstd.long_distance_matching,
+                window_log: check("zstd.window_log", zstd.window_log, 10..=27)?,
+            },
+            gzip: GzipSettings {
+                level: check("gzip.level", gzip.level, 0..=9)?.unwrap_or(default.gzip.level),
+            },
+            brotli: BrotliSettings {
+                quality: check("brotli.quality", brotli.quality, 0..=11)?
+                    .unwrap_or(default.brotli.quality),
+                window: check("brotli.window", brotli.window, 10..=24)?
+                    .unwrap_or(default.brotli.window),
+            },
+            min_ratio,
+            store_compressed,
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum LongDistanceMatching {
+    #[default]
+    No = 0,
+    Yes = 1,
+}
+
+impl BoolLikeEnum for LongDistanceMatching {
+    fn yes() -> Self {
+        Self::Yes
+    }
+
+    fn no() -> Self {
+        Self::No
+    }
+}
+
+impl FromMeta for LongDistanceMatching {
+    fn from_bool(value: bool) -> darling::Result<Self> {
+        Self::darling_from_bool(value)
+    }
+
+    fn from_char(value: char) -> darling::Result<Self> {
+        Self::darling_from_char(value)
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Self::darling_from_string(value)
+    }
+
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::Yes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use darling::FromMeta;
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    use super::{
+        BrotliSettings, CompressionAttr, CompressionKind, CompressionSettings, GzipSettings,
+        LongDistanceMatching, ZstdSettings,
+    };
+
+    #[test]
+    fn from_meta() {
+        let attr = CompressionAttr::from_meta(&parse_quote!(compression(
+            zstd(level = 3, long_distance_matching, window_log = 27),
+            gzip(level = 6),
+            brotli(quality = 5, window = 20),
+        )))
+        .unwrap();
+        assert_eq!(
+            CompressionSettings::try_from(attr).unwrap(),
+            CompressionSettings {
+                zstd: ZstdSettings {
+                    level: 3,
+                    long_distance_matching: LongDistanceMatching::Yes,
+                    window_log: Some(27),
+                },
+                gzip: GzipSettings { level: 6 },
+                brotli: BrotliSettings {
+                    quality: 5,
+                    window: 20,
+                },
+                min_ratio: None,
+                store_compressed: None,
+            }
+        );
+    }
+
+    #[test]
+    fn store_compressed() {
+        let attr =
+            CompressionAttr::from_meta(&parse_quote!(compression(store_compressed = "zstd")))
+                .unwrap();
+        assert_eq!(attr.store_compressed, Some(CompressionKind::Zstd));
+
+        let result = CompressionSettings::try_from(attr);
+        if cfg!(feature = "zstd-decompress") {
+            assert_eq!(
+                result.unwrap().store_compressed,
+                Some(CompressionKind::Zstd)
+            );
+        } else {
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                "`store_compressed = \"zstd\"` requires the `zstd-decompress` feature"
+            );
+        }
+
+        CompressionAttr::from_meta(&parse_quote!(compression(store_compressed = "lz4")))
+            .unwrap_err();
+    }
+
+    #[test]
+    fn min_ratio() {
+        let attr = CompressionAttr::from_meta(&parse_quote!(compression(min_ratio = 1.5))).unwrap();
+        let settings = CompressionSettings::try_from(attr).unwrap();
+        assert_eq!(settings.min_ratio, Some(1.5));
+        assert!(settings.is_worth(150, 100));
+        assert!(!settings.is_worth(149, 100));
+
+        let attr = CompressionAttr::from_meta(&parse_quote!(compression(min_ratio = 1.0))).unwrap();
+        let settings = CompressionSettings::try_from(attr).unwrap();
+        assert!(settings.is_worth(101, 100));
+        assert!(!settings.is_worth(100, 100));
+
+        assert!(CompressionSettings::default().is_worth(10, 100));
+
+        let attr = CompressionAttr::from_meta(&parse_quote!(compression(min_ratio = 0.5))).unwrap();
+        let err = CompressionSettings::try_from(attr).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`min_ratio` must be a finite number not less than 1, but it is 0.5"
+        );
+    }
+
+    #[test]
+    fn defaults() {
+        let attr = CompressionAttr::from_meta(&parse_quote!(compression(gzip(level = 1)))).unwrap();
+        let settings = CompressionSettings::try_from(attr).unwrap();
+        assert_eq!(settings.gzip, GzipSettings { level: 1 });
+        assert_eq!(settings.zstd, CompressionSettings::default().zstd);
+        assert_eq!(settings.brotli, CompressionSettings::default().brotli);
+
+        assert_eq!(
+            CompressionSettings::try_from(CompressionAttr::default()).unwrap(),
+            CompressionSettings::default()
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        let attr =
+            CompressionAttr::from_meta(&parse_quote!(compression(brotli(quality = 12)))).unwrap();
+        let err = CompressionSettings::try_from(attr).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`brotli.quality` must be in range 0..=11, but it is 12"
+        );
+
+        let attr = CompressionAttr::from_meta(&parse_quote!(compression(zstd(level = 0)))).unwrap();
+        CompressionSettings::try_from(attr).unwrap_err();
+
+        let attr =
+            CompressionAttr::from_meta(&parse_quote!(compression(zstd(window_log = 28)))).unwrap();
+        let err = CompressionSettings::try_from(attr).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`zstd.window_log` must be in range 10..=27, but it is 28"
+        );
+    }
+
+    #[test]
+    fn unknown_param() {
+        CompressionAttr::from_meta(&parse_quote!(compression(gzip(quality = 1)))).unwrap_err();
+    }
+}
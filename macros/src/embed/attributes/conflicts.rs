@@ -0,0 +1,34 @@
+use darling::FromMeta;
+
+/// How entries with the same relative path in several roots are handled
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, FromMeta)]
+pub enum Conflicts {
+    /// An entry of a later root shadows an entry of an earlier one. Dirs are merged
+    #[default]
+    #[darling(rename = "shadow")]
+    Shadow,
+
+    /// Shadowing is a compilation error. Dirs are still merged
+    #[darling(rename = "error")]
+    Error,
+}
+
+#[cfg(test)]
+mod tests {
+    use darling::FromMeta;
+    use syn::parse_quote;
+
+    use super::Conflicts;
+
+    #[test]
+    fn from_meta() {
+        let cases = [("shadow", Conflicts::Shadow), ("error", Conflicts::Error)];
+        for (value, expected) in cases {
+            let meta: syn::Meta = parse_quote!(conflicts = #value);
+            assert_eq!(Conflicts::from_meta(&meta).unwrap(), expected);
+        }
+
+        let meta: syn::Meta = parse_quote!(conflicts = "unknown");
+        assert!(Conflicts::from_meta(&meta).is_err());
+    }
+}
@@ -0,0 +1,110 @@
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// A `cfg` predicate under which a file content is read from the disk at runtime
+/// instead of being embedded into the binary
+#[derive(Debug, Clone)]
+pub struct DevMode(syn::Meta);
+
+impl DevMode {
+    pub fn predicate(&self) -> &syn::Meta {
+        &self.0
+    }
+
+    /// Generates two versions of an item: the first one is used if the predicate is true
+    /// and the second one otherwise
+    pub fn select(&self, dev: TokenStream, embedded: TokenStream) -> TokenStream {
+        let predicate = &self.0;
+        quote! {
+            #[cfg(#predicate)]
+            #dev
+
+            #[cfg(not(#predicate))]
+            #embedded
+        }
+    }
+
+    /// Keeps an item only if the predicate is true
+    pub fn dev_only(&self, item: TokenStream) -> TokenStream {
+        let predicate = &self.0;
+        quote! {
+            #[cfg(#predicate)]
+            #item
+        }
+    }
+
+    /// Keeps an item only if the predicate is false
+    pub fn embedded_only(&self, item: TokenStream) -> TokenStream {
+        let predicate = &self.0;
+        quote! {
+            #[cfg(not(#predicate))]
+            #item
+        }
+    }
+}
+
+impl FromMeta for DevMode {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        syn::parse_str::<syn::Meta>(value)
+            .map(Self)
+            .map_err(|e| darling::Error::custom(format!("invalid cfg predicate: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use darling::FromMeta;
+    use pretty_assertions::assert_eq;
+    use quote::{ToTokens, quote};
+    use syn::parse_quote;
+
+    use super::DevMode;
+
+    #[test]
+    fn from_meta() {
+        let value = DevMode::from_meta(&parse_quote!(value = "debug_assertions")).unwrap();
+        assert_eq!(
+            value.predicate().to_token_stream().to_string(),
+            quote!(debug_assertions).to_string()
+        );
+
+        let value = DevMode::from_meta(&parse_quote!(
+            value = "all(debug_assertions, feature = \"dev\")"
+        ))
+        .unwrap();
+        assert_eq!(
+            value.predicate().to_token_stream().to_string(),
+            quote!(all(debug_assertions, feature = "dev")).to_string()
+        );
+    }
+
+    #[test]
+    fn from_meta_invalid() {
+        let err = DevMode::from_meta(&parse_quote!(value = "debug assertions")).unwrap_err();
+        assert!(format!("{err}").contains("invalid cfg predicate"));
+    }
+
+    #[test]
+    fn from_meta_unsupported_word() {
+        DevMode::from_meta(&parse_quote!(value)).unwrap_err();
+    }
+
+    #[test]
+    fn select() {
+        let value = DevMode::from_meta(&parse_quote!(value = "debug_assertions")).unwrap();
+        let dev = quote! { fn a() {} };
+        let embedded = quote! { fn b() {} };
+        assert_eq!(
+            value.select(dev, embedded).to_string(),
+            quote! {
+                #[cfg(debug_assertions)]
+                fn a() {}
+
+                #[cfg(not(debug_assertions))]
+                fn b() {}
+            }
+            .to_string()
+        );
+    }
+}
@@ -6,9 +6,9 @@ use crate::{
     embed::{EntryTokens, GenerateContext},
     embedded_traits::{
         EMBEDED_TRAITS, EmbeddedTrait, ResolveEmbeddedTraitError, TraitAttr, debug::DebugTrait,
-        direct_child_count::DirectChildCountTrait, entries::EntriesTrait, hashes::ids::*,
-        index::IndexTrait, meta::MetaTrait, path::PathTrait,
-        recursive_child_count::RecursiveChildCountTrait,
+        direct_child_count::DirectChildCountTrait, entries::EntriesTrait, find::FindTrait,
+        hashes::ids::*, index::IndexTrait, manifest::ManifestTrait, meta::MetaTrait,
+        path::PathTrait, recursive_child_count::RecursiveChildCountTrait, walk::WalkTrait,
     },
     main_trait_data::{MainTrait, MainTraitData},
     marker_traits::{MarkerTrait, child_of::ChildOfMarker},
@@ -17,6 +17,7 @@ use crate::{
 use super::{
     derive_default_traits::DeriveDefaultTraits,
     field::{CreateFieldTraitsError, FieldAttr, FieldTraits},
+    mount::{Mount, MountAttr, ParseMountAttrError},
     path_match::{PathMatcher, PathMatcherAttr},
 };
 
@@ -42,6 +43,10 @@ pub struct DirAttr {
 
     #[darling(default, flatten)]
     matcher: PathMatcherAttr,
+
+    /// Separately derived embeds, which are dirs of this one
+    #[darling(default, multiple, rename = "mount")]
+    mounts: Vec<MountAttr>,
 }
 
 #[derive(Debug, FromMeta, Clone, Copy, PartialEq, Eq)]
@@ -67,6 +72,15 @@ pub enum DirEmbeddedTrait {
     #[darling(rename = "RecursiveChildCount")]
     RecursiveChildCount,
 
+    #[darling(rename = "Walk")]
+    Walk,
+
+    #[darling(rename = "Find")]
+    Find,
+
+    #[darling(rename = "Manifest")]
+    Manifest,
+
     #[darling(rename = "Md5")]
     Md5,
 
@@ -108,9 +122,12 @@ impl DirEmbeddedTrait {
             Self::Entries => Ok(&EntriesTrait),
             Self::Index => Ok(&IndexTrait),
             Self::Meta => Ok(&MetaTrait),
+            Self::Manifest => Ok(&ManifestTrait),
             Self::Debug => Ok(&DebugTrait),
             Self::DirectChildCount => Ok(&DirectChildCountTrait),
             Self::RecursiveChildCount => Ok(&RecursiveChildCountTrait),
+            Self::Walk => Ok(&WalkTrait),
+            Self::Find => Ok(&FindTrait),
 
             Self::Md5 => EMBEDED_TRAITS.get_hash_trait(MD5).map_err(Into::into),
             Self::Sha1 => EMBEDED_TRAITS.get_hash_trait(SHA1).map_err(Into::into),
@@ -157,6 +174,7 @@ pub struct DirTrait {
     fields: FieldTraits,
     markers: Vec<&'static dyn MarkerTrait>,
     matcher: PathMatcher,
+    mounts: Vec<Mount>,
 }
 
 impl MainTrait for DirTrait {
@@ -195,6 +213,7 @@ impl From<MainTraitData> for DirTrait {
             fields,
             markers,
             matcher,
+            mounts: Vec::new(),
         }
     }
 }
@@ -206,12 +225,16 @@ pub enum ParseDirAttrError {
 
     #[display("unable to create field traits: {_0}")]
     CreateFieldTraits(CreateFieldTraitsError),
+
+    #[display("unable to parse the `mount` attribute: {_0}")]
+    ParseMount(ParseMountAttrError),
 }
 
 impl TryFrom<DirAttr> for DirTrait {
     type Error = <Self as MainTrait>::Error;
     fn try_from(value: DirAttr) -> Result<Self, Self::Error> {
-        Self::create(
+        let mounts = Mount::create(value.mounts)?;
+        let mut dir = Self::create(
             value.derive_default_traits,
             value.embedded_traits,
             value.markers,
@@ -219,7 +242,20 @@ impl TryFrom<DirAttr> for DirTrait {
             value.field_factory_trait_name,
             value.fields,
             value.matcher,
-        )
+        )?;
+        dir.mounts = mounts;
+        Ok(dir)
+    }
+}
+
+impl DirTrait {
+    pub fn mounts(&self) -> &[Mount] {
+        &self.mounts
+    }
+
+    /// Mounts, which are direct children of the dir with the relative path
+    pub fn mounts_of<'a>(&'a self, dir: &'a str) -> impl Iterator<Item = &'a Mount> + 'a {
+        self.mounts.iter().filter(move |m| m.parent() == dir)
     }
 }
 
@@ -262,10 +298,11 @@ impl TraitAttr for DirTrait {
         entries: &[EntryTokens],
     ) -> proc_macro2::TokenStream {
         let struct_ident = &ctx.entry_struct_ident();
-        let methods = entries.iter().fold(quote! {}, |mut acc, entry| {
-            let EntryTokens {
-                struct_path, field, ..
-            } = entry;
+        let fields = entries
+            .iter()
+            .map(|e| (&e.struct_path, &e.field))
+            .chain(ctx.mounts.iter().map(|m| (&m.struct_path, &m.field)));
+        let methods = fields.fold(quote! {}, |mut acc, (struct_path, field)| {
             acc.extend(quote! {
                 pub fn #field(&self) -> &'static #struct_path {
                     &#struct_path
@@ -301,7 +338,8 @@ mod tests {
             trait_name = TraitName,
             field_factory_trait_name = FieldFactory,
             derive(Path),
-            derive(Entries)
+            derive(Entries),
+            mount(path = "sub", embed = crate::Other)
         ));
 
         let result = DirAttr::from_meta(&meta).unwrap();
@@ -320,6 +358,7 @@ mod tests {
             result.embedded_traits,
             vec![DirEmbeddedTrait::Path, DirEmbeddedTrait::Entries]
         );
+        assert_eq!(result.mounts.len(), 1);
     }
 
     #[test]
@@ -1,17 +1,29 @@
-use std::path::PathBuf;
+use std::{borrow::Cow, path::PathBuf};
 
 use crate::embed::GenerateContext;
+use crate::embed::bool_like_enum::BoolLikeEnum;
 use crate::embedded_traits::TraitAttr;
-use crate::fs::{ExpandPathError, expand_and_canonicalize, get_env};
+use crate::fs::{
+    ExpandPathError, ReadArchiveError, Root, expand, expand_and_canonicalize, get_env,
+};
 
+use super::conflicts::Conflicts;
+use super::dev_mode::DevMode;
 use super::dir::{DirAttr, DirTrait, ParseDirAttrError};
 use super::entry::{EntryAttr, EntryStruct};
+use super::entry_enum::EntryEnumAttr;
 use super::file::{FileAttr, FileTrait, ParseFileAttrError};
+use super::file_ids::FileIds;
+use super::index::{IndexAttr, IndexSettings, ParseIndexAttrError};
+use super::mount::mount_path_segments;
+use super::respect_ignore_files::RespectIgnoreFiles;
 use super::support_alt_separator::SupportAltSeparator;
+use super::symlinks::Symlinks;
 use super::with_extension::WithExtension;
 use darling::FromDeriveInput;
 use embed_it_utils::entry::{Entry, EntryKind};
 use proc_macro2::TokenStream;
+use quote::format_ident;
 use syn::{Ident, parse_quote};
 
 #[derive(Debug, FromDeriveInput)]
@@ -19,7 +31,15 @@ use syn::{Ident, parse_quote};
 pub struct EmbedInput {
     pub ident: syn::Ident,
 
-    pub path: String,
+    /// Roots, which are merged into one tree. An entry of a later root
+    /// shadows an entry of an earlier one with the same relative path.
+    /// A root may be a dir or a `.tar`, `.tar.gz`, `.tgz` or `.zip` archive
+    #[darling(multiple)]
+    pub path: Vec<String>,
+
+    /// How entries with the same relative path in several roots are handled. Default: `shadow`
+    #[darling(default)]
+    pub conflicts: Conflicts,
 
     #[darling(default)]
     pub with_extension: WithExtension,
@@ -29,6 +49,24 @@ pub struct EmbedInput {
     #[darling(default)]
     pub support_alt_separator: SupportAltSeparator,
 
+    /// A `cfg` predicate (e.g. `"debug_assertions"`). If it is true,
+    /// a file content is read from the disk at runtime. Default: `None`
+    #[darling(default)]
+    pub dev_mode: Option<DevMode>,
+
+    /// How symlinks are handled. Default: `follow`
+    #[darling(default)]
+    pub symlinks: Symlinks,
+
+    /// If true, entries matched by `.gitignore` and `.ignore` files
+    /// inside the root are not embedded. Default: `false`
+    #[darling(default)]
+    pub respect_ignore_files: RespectIgnoreFiles,
+
+    /// Settings of the `Index` trait
+    #[darling(default)]
+    pub index: IndexAttr,
+
     #[darling(default)]
     pub dir: DirAttr,
 
@@ -37,14 +75,45 @@ pub struct EmbedInput {
 
     #[darling(default)]
     pub entry: EntryAttr,
+
+    /// If set, an enum with a variant per embedded file is generated. Default: `None`
+    #[darling(default)]
+    pub file_enum: Option<EntryEnumAttr>,
+
+    /// If set, an enum with a variant per embedded dir (except the root) is generated. Default: `None`
+    #[darling(default)]
+    pub dir_enum: Option<EntryEnumAttr>,
+
+    /// If true, every file struct gets `const ID: u32` and the root struct gets
+    /// `FILES` and `by_id`. Default: `false`
+    #[darling(default)]
+    pub file_ids: FileIds,
+
+    /// A path of a JSON file, which is written with the information about embedded entries.
+    /// It may contain environment variables like `path`. Default: `None`
+    #[darling(default)]
+    pub manifest: Option<String>,
+
+    /// A module with the derive of another embed, whose traits and entry structs are
+    /// imported instead of generated. It is required to mount the embed into that one. Default: `None`
+    #[darling(default)]
+    pub definitions: Option<syn::Path>,
+
+    /// The `/` separated path, at which the embed is mounted into another one.
+    /// Paths of its entries start with it. Default: `None`
+    #[darling(default)]
+    pub mounted_at: Option<String>,
 }
 
 #[derive(Debug)]
 pub struct GenerationSettings {
     pub main_struct_ident: syn::Ident,
 
-    /// The absolute fs path for `path` attribute
-    pub root: PathBuf,
+    /// Dirs and archives for `path` attributes in the order of precedence
+    pub roots: Vec<Root>,
+
+    /// How entries with the same relative path in several roots are handled
+    pub conflicts: Conflicts,
 
     /// Should we use extensions in idents
     pub with_extension: WithExtension,
@@ -53,6 +122,19 @@ pub struct GenerationSettings {
     /// will be replaced by `/`
     pub support_alt_separator: SupportAltSeparator,
 
+    /// If set, a file content is read from the disk at runtime
+    /// when the `cfg` predicate is true
+    pub dev_mode: Option<DevMode>,
+
+    /// How symlinks are handled
+    pub symlinks: Symlinks,
+
+    /// Should we skip entries matched by ignore files
+    pub respect_ignore_files: RespectIgnoreFiles,
+
+    /// Settings of the `Index` trait
+    pub index: IndexSettings,
+
     /// Information about the `Dir` trait
     pub dir: DirTrait,
 
@@ -61,6 +143,24 @@ pub struct GenerationSettings {
 
     /// Information about the `Entry` struct
     pub entry: EntryStruct,
+
+    /// The name of the enum of all files, if it is generated
+    pub file_enum: Option<Ident>,
+
+    /// The name of the enum of all dirs, if it is generated
+    pub dir_enum: Option<Ident>,
+
+    /// Should we generate numeric ids of files
+    pub file_ids: FileIds,
+
+    /// The fs path of the JSON manifest, if it is written
+    pub manifest: Option<PathBuf>,
+
+    /// The module, which the traits and the entry structs are imported from
+    pub definitions: Option<syn::Path>,
+
+    /// The normalized path, at which the embed is mounted into another one
+    pub mounted_at: Option<String>,
 }
 
 #[derive(Debug, derive_more::Display)]
@@ -123,6 +223,15 @@ impl GenerationSettings {
         Ok(result)
     }
 
+    /// The path of an entry relative to the root of the embed, which it is mounted into, if any
+    pub fn mounted_path<'a>(&self, relative: &'a str) -> Cow<'a, str> {
+        match &self.mounted_at {
+            Some(mounted_at) if relative.is_empty() => Cow::Owned(mounted_at.clone()),
+            Some(mounted_at) => Cow::Owned(format!("{mounted_at}/{relative}")),
+            None => Cow::Borrowed(relative),
+        }
+    }
+
     /// The path of the type which is `Entry::Dir($path)`
     pub fn dir_entry_param(&self, level: usize) -> syn::Path {
         GenerateContext::make_nested_path(level, self.entry.dir_struct_ident().clone())
@@ -142,34 +251,142 @@ impl GenerationSettings {
 
 #[derive(Debug, derive_more::Display)]
 pub enum ParseEmbedInputError {
+    #[display("at least one `path` is required")]
+    NoPath,
+
     #[display("unable to expand the path: {_0}")]
     ExpandPath(ExpandPathError),
 
+    #[display("unable to read the archive: {_0}")]
+    ReadArchive(ReadArchiveError),
+
+    #[display("unable to expand the manifest path: {_0}")]
+    ExpandManifestPath(ExpandPathError),
+
     #[display("unable to parse the `dir` attribute: {_0}")]
     ParseDir(ParseDirAttrError),
 
     #[display("unable to parse the `file` attribute: {_0}")]
     ParseFile(ParseFileAttrError),
+
+    #[display("unable to parse the `index` attribute: {_0}")]
+    ParseIndex(ParseIndexAttrError),
+
+    #[display("`dev_mode` requires the `std` feature")]
+    DevModeRequiresStd,
+
+    #[display("`respect_ignore_files` requires the `ignore` feature")]
+    RespectIgnoreFilesRequiresIgnore,
+
+    #[display(
+        "`{_0}` is not supported with `mount`, it is generated at compile time without mounted entries"
+    )]
+    UnsupportedWithMount(String),
+
+    #[display("`mounted_at` `{_0}` must be a relative path without `.` and `..` segments")]
+    InvalidMountedAt(String),
+}
+
+/// Mounted embeds are separately derived, so only traits which delegate to them or
+/// build their data from the mounted data at compile time are supported
+fn check_mounts_support(settings: &GenerationSettings) -> Result<(), ParseEmbedInputError> {
+    if settings.dir.mounts().is_empty() {
+        return Ok(());
+    }
+    if let Some(id) = settings
+        .dir
+        .embedded_traits()
+        .map(|t| t.id())
+        .find(|id| *id == "Find" || *id == "Manifest" || id.starts_with("Hash("))
+    {
+        return Err(ParseEmbedInputError::UnsupportedWithMount(format!(
+            "dir(derive({id}))"
+        )));
+    }
+    let options = [
+        ("file_ids", settings.file_ids.as_bool()),
+        ("file_enum", settings.file_enum.is_some()),
+        ("dir_enum", settings.dir_enum.is_some()),
+        ("manifest", settings.manifest.is_some()),
+    ];
+    match options.into_iter().find(|(_, enabled)| *enabled) {
+        Some((option, _)) => Err(ParseEmbedInputError::UnsupportedWithMount(
+            option.to_owned(),
+        )),
+        None => Ok(()),
+    }
 }
 
 impl TryFrom<EmbedInput> for GenerationSettings {
     type Error = ParseEmbedInputError;
 
     fn try_from(value: EmbedInput) -> Result<Self, Self::Error> {
-        let root = expand_and_canonicalize(&value.path, get_env)
-            .map_err(ParseEmbedInputError::ExpandPath)?;
+        if value.path.is_empty() {
+            return Err(ParseEmbedInputError::NoPath);
+        }
+        let roots = value
+            .path
+            .iter()
+            .map(|path| {
+                let path = expand_and_canonicalize(path, get_env)
+                    .map_err(ParseEmbedInputError::ExpandPath)?;
+                Root::open(path, value.symlinks).map_err(ParseEmbedInputError::ReadArchive)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
         let dir = DirTrait::try_from(value.dir).map_err(ParseEmbedInputError::ParseDir)?;
         let file = FileTrait::try_from(value.file).map_err(ParseEmbedInputError::ParseFile)?;
         let entry = EntryStruct::from(value.entry);
+        let index =
+            IndexSettings::try_from(value.index).map_err(ParseEmbedInputError::ParseIndex)?;
+        if !cfg!(feature = "std") && value.dev_mode.is_some() {
+            return Err(ParseEmbedInputError::DevModeRequiresStd);
+        }
+        if !cfg!(feature = "ignore") && value.respect_ignore_files.as_bool() {
+            return Err(ParseEmbedInputError::RespectIgnoreFilesRequiresIgnore);
+        }
 
-        Ok(Self {
+        let manifest = value
+            .manifest
+            .map(|path| expand(&path, get_env).map(PathBuf::from))
+            .transpose()
+            .map_err(ParseEmbedInputError::ExpandManifestPath)?;
+
+        let file_enum = value
+            .file_enum
+            .map(|v| v.ident(|| format_ident!("{}File", value.ident)));
+        let dir_enum = value
+            .dir_enum
+            .map(|v| v.ident(|| format_ident!("{}Dir", value.ident)));
+
+        let mounted_at = value
+            .mounted_at
+            .map(|path| match mount_path_segments(&path) {
+                Some(segments) => Ok(segments.join("/")),
+                None => Err(ParseEmbedInputError::InvalidMountedAt(path)),
+            })
+            .transpose()?;
+
+        let settings = Self {
             main_struct_ident: value.ident,
-            root,
+            roots,
+            conflicts: value.conflicts,
             with_extension: value.with_extension,
             support_alt_separator: value.support_alt_separator,
+            dev_mode: value.dev_mode,
+            symlinks: value.symlinks,
+            respect_ignore_files: value.respect_ignore_files,
+            index,
             dir,
             file,
             entry,
-        })
+            file_enum,
+            dir_enum,
+            file_ids: value.file_ids,
+            manifest,
+            definitions: value.definitions,
+            mounted_at,
+        };
+        check_mounts_support(&settings)?;
+        Ok(settings)
     }
 }
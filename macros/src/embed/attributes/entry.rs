@@ -106,7 +106,7 @@ impl EntryStruct {
             pub trait #entry_file_trait: #file_bounds {}
 
             #[derive(#dir_derive)]
-            pub struct #entry_dir_struct(&'static dyn #entry_dir_trait);
+            pub struct #entry_dir_struct(pub &'static dyn #entry_dir_trait);
 
             #[automatically_derived]
             impl #entry_dir_struct {
@@ -116,7 +116,7 @@ impl EntryStruct {
             }
 
             #[automatically_derived]
-            impl ::std::ops::Deref for #entry_dir_struct {
+            impl ::core::ops::Deref for #entry_dir_struct {
                 type Target = dyn #entry_dir_trait;
 
                 fn deref(&self) -> &Self::Target {
@@ -125,10 +125,10 @@ impl EntryStruct {
             }
 
             #[derive(#file_derive)]
-            pub struct #entry_file_struct(&'static dyn #entry_file_trait);
+            pub struct #entry_file_struct(pub &'static dyn #entry_file_trait);
 
             #[automatically_derived]
-            impl ::std::ops::Deref for #entry_file_struct {
+            impl ::core::ops::Deref for #entry_file_struct {
                 type Target = dyn #entry_file_trait;
 
                 fn deref(&self) -> &Self::Target {
@@ -0,0 +1,53 @@
+use darling::FromMeta;
+use syn::Ident;
+
+/// The `file_enum`/`dir_enum` attribute: a word to generate an enum with the default name
+/// or a string with the name of the enum
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EntryEnumAttr {
+    pub name: Option<Ident>,
+}
+
+impl EntryEnumAttr {
+    /// The name of the enum, `default` if it is not set explicitly
+    pub fn ident(self, default: impl FnOnce() -> Ident) -> Ident {
+        self.name.unwrap_or_else(default)
+    }
+}
+
+impl FromMeta for EntryEnumAttr {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self { name: None })
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        syn::parse_str::<Ident>(value)
+            .map(|name| Self { name: Some(name) })
+            .map_err(|e| darling::Error::custom(format!("invalid enum name: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use darling::FromMeta;
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    use super::EntryEnumAttr;
+
+    #[test]
+    fn from_meta() {
+        assert_eq!(
+            EntryEnumAttr::from_meta(&parse_quote!(file_enum)).unwrap(),
+            EntryEnumAttr { name: None }
+        );
+        assert_eq!(
+            EntryEnumAttr::from_meta(&parse_quote!(file_enum = "AssetFile")).unwrap(),
+            EntryEnumAttr {
+                name: Some(parse_quote!(AssetFile))
+            }
+        );
+        EntryEnumAttr::from_meta(&parse_quote!(file_enum = "Asset File")).unwrap_err();
+        EntryEnumAttr::from_meta(&parse_quote!(file_enum = true)).unwrap_err();
+    }
+}
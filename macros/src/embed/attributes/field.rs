@@ -134,6 +134,9 @@ pub struct FieldTraits {
 pub enum CreateFieldTraitsError {
     #[display("duplicate trait name: {_0}")]
     DuplicateTraitName(DuplicateTraitName),
+
+    #[display("fields require the `std` feature")]
+    RequiresStd,
 }
 
 #[derive(Debug)]
@@ -159,6 +162,10 @@ The error has been produced by a trait name '{}' on a field '{}'"#,
 
 impl FieldTraits {
     pub fn create(attrs: Vec<FieldAttr>) -> Result<Self, CreateFieldTraitsError> {
+        if !cfg!(feature = "std") && !attrs.is_empty() {
+            return Err(CreateFieldTraitsError::RequiresStd);
+        }
+
         let fields_len = attrs.len();
         let mut by_trait_name = HashMap::new();
         let mut traits = Vec::with_capacity(fields_len);
@@ -9,8 +9,12 @@ use crate::{
         compression::ids::{BROTLI, GZIP, ZSTD},
         content::ContentTrait,
         debug::DebugTrait,
+        hashed_name::HashedNameTrait,
         hashes::ids::*,
+        link_target::LinkTargetTrait,
+        manifest::ManifestTrait,
         meta::MetaTrait,
+        mime::MimeTrait,
         path::PathTrait,
         str_content::StrContentTrait,
     },
@@ -19,9 +23,12 @@ use crate::{
 };
 
 use super::{
+    compression::{CompressionAttr, CompressionSettings, ParseCompressionAttrError},
     derive_default_traits::DeriveDefaultTraits,
     entry::EntryStruct,
     field::{CreateFieldTraitsError, FieldAttr, FieldTraits},
+    hashed_name::{HashedNameAttr, HashedNameSettings, ParseHashedNameAttrError},
+    mime::{MimeAttr, MimeSettings},
     path_match::{PathMatcher, PathMatcherAttr},
 };
 
@@ -47,6 +54,15 @@ pub struct FileAttr {
 
     #[darling(default, flatten)]
     matcher: PathMatcherAttr,
+
+    #[darling(default)]
+    mime: MimeAttr,
+
+    #[darling(default)]
+    compression: CompressionAttr,
+
+    #[darling(default)]
+    hashed_name: HashedNameAttr,
 }
 
 #[derive(Debug, FromMeta, Clone, Copy, PartialEq, Eq)]
@@ -66,6 +82,18 @@ pub enum FileEmbeddedTrait {
     #[darling(rename = "Debug")]
     Debug,
 
+    #[darling(rename = "Mime")]
+    Mime,
+
+    #[darling(rename = "HashedName")]
+    HashedName,
+
+    #[darling(rename = "LinkTarget")]
+    LinkTarget,
+
+    #[darling(rename = "Manifest")]
+    Manifest,
+
     #[darling(rename = "Md5")]
     Md5,
 
@@ -99,6 +127,9 @@ pub enum FileEmbeddedTrait {
     #[darling(rename = "Blake3")]
     Blake3,
 
+    #[darling(rename = "Sri")]
+    Sri(SriHash),
+
     #[darling(rename = "Gzip")]
     Gzip,
 
@@ -109,6 +140,19 @@ pub enum FileEmbeddedTrait {
     Brotli,
 }
 
+/// A hash of the `Sri` trait
+#[derive(Debug, FromMeta, Clone, Copy, PartialEq, Eq)]
+pub enum SriHash {
+    #[darling(rename = "Sha2_256")]
+    Sha2_256,
+
+    #[darling(rename = "Sha2_384")]
+    Sha2_384,
+
+    #[darling(rename = "Sha2_512")]
+    Sha2_512,
+}
+
 impl FileEmbeddedTrait {
     fn to_embedded_trait(self) -> Result<&'static dyn EmbeddedTrait, ResolveEmbeddedTraitError> {
         match self {
@@ -116,7 +160,11 @@ impl FileEmbeddedTrait {
             Self::Content => Ok(&ContentTrait),
             Self::StrContent => Ok(&StrContentTrait),
             Self::Meta => Ok(&MetaTrait),
+            Self::Manifest => Ok(&ManifestTrait),
             Self::Debug => Ok(&DebugTrait),
+            Self::Mime => Ok(&MimeTrait),
+            Self::HashedName => Ok(&HashedNameTrait),
+            Self::LinkTarget => Ok(&LinkTargetTrait),
 
             Self::Md5 => EMBEDED_TRAITS.get_hash_trait(MD5).map_err(Into::into),
             Self::Sha1 => EMBEDED_TRAITS.get_hash_trait(SHA1).map_err(Into::into),
@@ -130,6 +178,16 @@ impl FileEmbeddedTrait {
             Self::Sha3_512 => EMBEDED_TRAITS.get_hash_trait(SHA3_512).map_err(Into::into),
             Self::Blake3 => EMBEDED_TRAITS.get_hash_trait(BLAKE3).map_err(Into::into),
 
+            Self::Sri(SriHash::Sha2_256) => EMBEDED_TRAITS
+                .get_hash_trait(SRI_SHA2_256)
+                .map_err(Into::into),
+            Self::Sri(SriHash::Sha2_384) => EMBEDED_TRAITS
+                .get_hash_trait(SRI_SHA2_384)
+                .map_err(Into::into),
+            Self::Sri(SriHash::Sha2_512) => EMBEDED_TRAITS
+                .get_hash_trait(SRI_SHA2_512)
+                .map_err(Into::into),
+
             Self::Gzip => EMBEDED_TRAITS.get_compress_trait(GZIP).map_err(Into::into),
             Self::Zstd => EMBEDED_TRAITS.get_compress_trait(ZSTD).map_err(Into::into),
             Self::Brotli => EMBEDED_TRAITS
@@ -169,6 +227,26 @@ pub struct FileTrait {
     trait_name: Ident,
     field_factory_trait_name: Ident,
     matcher: PathMatcher,
+    mime: MimeSettings,
+    compression: CompressionSettings,
+    hashed_name: HashedNameSettings,
+}
+
+impl FileTrait {
+    /// Settings of the `Mime` trait
+    pub fn mime(&self) -> &MimeSettings {
+        &self.mime
+    }
+
+    /// Parameters of compression traits
+    pub fn compression(&self) -> &CompressionSettings {
+        &self.compression
+    }
+
+    /// Settings of the `HashedName` trait
+    pub fn hashed_name(&self) -> &HashedNameSettings {
+        &self.hashed_name
+    }
 }
 
 #[derive(Debug, derive_more::Display, derive_more::From)]
@@ -178,6 +256,16 @@ pub enum ParseFileAttrError {
 
     #[display("unable to create field traits: {_0}")]
     CreateFieldTraits(CreateFieldTraitsError),
+
+    #[display("unable to parse compression parameters: {_0}")]
+    ParseCompression(ParseCompressionAttrError),
+
+    #[display("unable to parse hashed name parameters: {_0}")]
+    ParseHashedName(ParseHashedNameAttrError),
+
+    #[display("only one `Sri` trait can be derived, but found {_0}")]
+    #[from(ignore)]
+    MultipleSri(usize),
 }
 
 impl MainTrait for FileTrait {
@@ -212,6 +300,9 @@ impl From<MainTraitData> for FileTrait {
             field_factory_trait_name,
             markers,
             matcher,
+            mime: MimeSettings::default(),
+            compression: CompressionSettings::default(),
+            hashed_name: HashedNameSettings::default(),
         }
     }
 }
@@ -219,7 +310,16 @@ impl From<MainTraitData> for FileTrait {
 impl TryFrom<FileAttr> for FileTrait {
     type Error = <Self as MainTrait>::Error;
     fn try_from(value: FileAttr) -> Result<FileTrait, Self::Error> {
-        Self::create(
+        let sri_count = value
+            .embedded_traits
+            .iter()
+            .filter(|t| matches!(t, FileEmbeddedTrait::Sri(_)))
+            .count();
+        if sri_count > 1 {
+            return Err(ParseFileAttrError::MultipleSri(sri_count));
+        }
+
+        let mut file = Self::create(
             value.derive_default_traits,
             value.embedded_traits,
             value.markers,
@@ -227,7 +327,11 @@ impl TryFrom<FileAttr> for FileTrait {
             value.field_factory_trait_name,
             value.fields,
             value.matcher,
-        )
+        )?;
+        file.mime = value.mime.into();
+        file.compression = value.compression.try_into()?;
+        file.hashed_name = value.hashed_name.try_into()?;
+        Ok(file)
     }
 }
 
@@ -276,10 +380,11 @@ mod tests {
     use syn::{Ident, parse_quote};
 
     use crate::embed::attributes::{
-        derive_default_traits::DeriveDefaultTraits, file::FileEmbeddedTrait,
+        derive_default_traits::DeriveDefaultTraits,
+        file::{FileEmbeddedTrait, SriHash},
     };
 
-    use super::FileAttr;
+    use super::{FileAttr, FileTrait};
 
     #[test]
     fn parse_all_fields() {
@@ -329,4 +434,25 @@ mod tests {
         assert_eq!(result.field_factory_trait_name, None);
         assert_eq!(result.embedded_traits, Vec::default());
     }
+
+    #[test]
+    fn sri() {
+        let meta: syn::Meta = parse_quote!(file(derive(Sri(Sha2_384))));
+        let result = FileAttr::from_meta(&meta).unwrap();
+        assert_eq!(
+            result.embedded_traits,
+            vec![FileEmbeddedTrait::Sri(SriHash::Sha2_384)]
+        );
+
+        let meta: syn::Meta = parse_quote!(file(derive(Sri(Sha2_256)), derive(Sri(Sha2_512))));
+        let result = FileAttr::from_meta(&meta).unwrap();
+        let err = FileTrait::try_from(result).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "only one `Sri` trait can be derived, but found 2"
+        );
+
+        let meta: syn::Meta = parse_quote!(file(derive(Sri(Md5))));
+        FileAttr::from_meta(&meta).unwrap_err();
+    }
 }
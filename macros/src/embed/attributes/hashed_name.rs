@@ -0,0 +1,238 @@
+use darling::FromMeta;
+
+#[derive(Debug, Default, Clone, FromMeta)]
+pub struct HashedNameAttr {
+    /// The hash of a file content, which is inserted into the name. Default: `"sha2_256"`
+    #[darling(default)]
+    pub hash: Option<HashedNameHash>,
+
+    /// How many hex chars of the hash are inserted into the name. Default: 8
+    #[darling(default)]
+    pub len: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromMeta)]
+pub enum HashedNameHash {
+    #[darling(rename = "md5")]
+    Md5,
+
+    #[darling(rename = "sha1")]
+    Sha1,
+
+    #[darling(rename = "sha2_224")]
+    Sha2_224,
+
+    #[darling(rename = "sha2_256")]
+    Sha2_256,
+
+    #[darling(rename = "sha2_384")]
+    Sha2_384,
+
+    #[darling(rename = "sha2_512")]
+    Sha2_512,
+
+    #[darling(rename = "sha3_224")]
+    Sha3_224,
+
+    #[darling(rename = "sha3_256")]
+    Sha3_256,
+
+    #[darling(rename = "sha3_384")]
+    Sha3_384,
+
+    #[darling(rename = "sha3_512")]
+    Sha3_512,
+
+    #[darling(rename = "blake3")]
+    Blake3,
+}
+
+impl HashedNameHash {
+    /// The value of the attribute
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::Md5 => "md5",
+            Self::Sha1 => "sha1",
+            Self::Sha2_224 => "sha2_224",
+            Self::Sha2_256 => "sha2_256",
+            Self::Sha2_384 => "sha2_384",
+            Self::Sha2_512 => "sha2_512",
+            Self::Sha3_224 => "sha3_224",
+            Self::Sha3_256 => "sha3_256",
+            Self::Sha3_384 => "sha3_384",
+            Self::Sha3_512 => "sha3_512",
+            Self::Blake3 => "blake3",
+        }
+    }
+
+    /// The feature, which is required to compute the hash
+    pub fn feature(self) -> &'static str {
+        match self {
+            Self::Md5 => "md5",
+            Self::Sha1 => "sha1",
+            Self::Sha2_224 | Self::Sha2_256 | Self::Sha2_384 | Self::Sha2_512 => "sha2",
+            Self::Sha3_224 | Self::Sha3_256 | Self::Sha3_384 | Self::Sha3_512 => "sha3",
+            Self::Blake3 => "blake3",
+        }
+    }
+
+    /// The length of the hash in hex chars
+    pub fn hex_len(self) -> usize {
+        match self {
+            Self::Md5 => 32,
+            Self::Sha1 => 40,
+            Self::Sha2_224 | Self::Sha3_224 => 56,
+            Self::Sha2_256 | Self::Sha3_256 | Self::Blake3 => 64,
+            Self::Sha2_384 | Self::Sha3_384 => 96,
+            Self::Sha2_512 | Self::Sha3_512 => 128,
+        }
+    }
+
+    fn is_enabled(self) -> bool {
+        match self {
+            Self::Md5 => cfg!(feature = "md5"),
+            Self::Sha1 => cfg!(feature = "sha1"),
+            Self::Sha2_224 | Self::Sha2_256 | Self::Sha2_384 | Self::Sha2_512 => {
+                cfg!(feature = "sha2")
+            }
+            Self::Sha3_224 | Self::Sha3_256 | Self::Sha3_384 | Self::Sha3_512 => {
+                cfg!(feature = "sha3")
+            }
+            Self::Blake3 => cfg!(feature = "blake3"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashedNameSettings {
+    pub hash: HashedNameHash,
+    pub len: usize,
+}
+
+impl HashedNameSettings {
+    /// Inserts the hash before the extension: `app.js` -> `app.<hash>.js`
+    pub fn hashed_file_name(&self, file_name: &str, hash: &[u8]) -> String {
+        let hash = hash.iter().map(|b| format!("{b:02x}")).collect::<String>();
+        let hash = &hash[..self.len];
+        match file_name.rsplit_once('.') {
+            Some((stem, ext)) if !stem.is_empty() => format!("{stem}.{hash}.{ext}"),
+            _ => format!("{file_name}.{hash}"),
+        }
+    }
+}
+
+impl Default for HashedNameSettings {
+    fn default() -> Self {
+        Self {
+            hash: HashedNameHash::Sha2_256,
+            len: 8,
+        }
+    }
+}
+
+#[derive(Debug, derive_more::Display)]
+pub enum ParseHashedNameAttrError {
+    #[display("`hash = \"{}\"` requires the `{}` feature", _0.id(), _0.feature())]
+    FeatureDisabled(HashedNameHash),
+
+    #[display("`len` must be in range 1..={max} for `{}`, but it is {value}", hash.id())]
+    Len {
+        hash: HashedNameHash,
+        value: usize,
+        max: usize,
+    },
+}
+
+impl TryFrom<HashedNameAttr> for HashedNameSettings {
+    type Error = ParseHashedNameAttrError;
+
+    fn try_from(value: HashedNameAttr) -> Result<Self, Self::Error> {
+        let default = Self::default();
+        if let Some(hash) = value.hash {
+            if !hash.is_enabled() {
+                return Err(ParseHashedNameAttrError::FeatureDisabled(hash));
+            }
+        }
+
+        let hash = value.hash.unwrap_or(default.hash);
+        let len = value.len.unwrap_or(default.len);
+        if len == 0 || len > hash.hex_len() {
+            return Err(ParseHashedNameAttrError::Len {
+                hash,
+                value: len,
+                max: hash.hex_len(),
+            });
+        }
+
+        Ok(Self { hash, len })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use darling::FromMeta;
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    use super::{HashedNameAttr, HashedNameHash, HashedNameSettings};
+
+    #[test]
+    fn from_meta() {
+        let attr =
+            HashedNameAttr::from_meta(&parse_quote!(hashed_name(hash = "md5", len = 12))).unwrap();
+        assert_eq!(attr.hash, Some(HashedNameHash::Md5));
+        assert_eq!(attr.len, Some(12));
+
+        let result = HashedNameSettings::try_from(attr);
+        if cfg!(feature = "md5") {
+            assert_eq!(
+                result.unwrap(),
+                HashedNameSettings {
+                    hash: HashedNameHash::Md5,
+                    len: 12
+                }
+            );
+        } else {
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                "`hash = \"md5\"` requires the `md5` feature"
+            );
+        }
+
+        HashedNameAttr::from_meta(&parse_quote!(hashed_name(hash = "crc32"))).unwrap_err();
+    }
+
+    #[test]
+    fn len() {
+        let attr = HashedNameAttr::from_meta(&parse_quote!(hashed_name(len = 0))).unwrap();
+        assert_eq!(
+            HashedNameSettings::try_from(attr).unwrap_err().to_string(),
+            "`len` must be in range 1..=64 for `sha2_256`, but it is 0"
+        );
+
+        let attr = HashedNameAttr::from_meta(&parse_quote!(hashed_name(len = 65))).unwrap();
+        HashedNameSettings::try_from(attr).unwrap_err();
+
+        let attr = HashedNameAttr::from_meta(&parse_quote!(hashed_name(len = 64))).unwrap();
+        assert_eq!(HashedNameSettings::try_from(attr).unwrap().len, 64);
+    }
+
+    #[test]
+    fn hashed_file_name() {
+        let settings = HashedNameSettings {
+            hash: HashedNameHash::Sha2_256,
+            len: 6,
+        };
+        let hash = [0x3f, 0xa2, 0xc1, 0x00, 0xff];
+        assert_eq!(settings.hashed_file_name("app.js", &hash), "app.3fa2c1.js");
+        assert_eq!(
+            settings.hashed_file_name("app.min.js", &hash),
+            "app.min.3fa2c1.js"
+        );
+        assert_eq!(
+            settings.hashed_file_name("LICENSE", &hash),
+            "LICENSE.3fa2c1"
+        );
+        assert_eq!(settings.hashed_file_name(".env", &hash), ".env.3fa2c1");
+    }
+}
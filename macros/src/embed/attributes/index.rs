@@ -0,0 +1,277 @@
+use darling::FromMeta;
+
+use crate::embed::bool_like_enum::BoolLikeEnum;
+
+#[derive(Debug, Default, Clone, FromMeta)]
+pub struct IndexAttr {
+    /// How the `Index` trait stores children.
+    /// Default: `hash_map` with the `std` feature and `sorted` without it
+    #[darling(default)]
+    pub kind: Option<IndexKind>,
+
+    /// Compare ASCII letters case-insensitively. Default: no
+    #[darling(default)]
+    pub case_insensitive: CaseInsensitive,
+
+    /// Skip leading `/`, duplicate separators and `.` segments, and reject paths with `..`. Default: no
+    #[darling(default)]
+    pub normalize_path: NormalizePath,
+
+    /// Decode `%XX` sequences. Default: no
+    #[darling(default)]
+    pub percent_decode: PercentDecode,
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexSettings {
+    /// How the `Index` trait stores children
+    pub kind: IndexKind,
+
+    pub case_insensitive: CaseInsensitive,
+    pub normalize_path: NormalizePath,
+    pub percent_decode: PercentDecode,
+}
+
+impl IndexSettings {
+    /// A key of the index for the relative path. Requested paths are normalized at runtime the same way
+    pub fn key(&self, relative_path: &str) -> String {
+        if self.case_insensitive.as_bool() {
+            relative_path.to_ascii_lowercase()
+        } else {
+            relative_path.to_owned()
+        }
+    }
+
+    /// Does a requested path need any processing except the alt separator
+    pub fn is_exact(&self) -> bool {
+        !self.case_insensitive.as_bool()
+            && !self.normalize_path.as_bool()
+            && !self.percent_decode.as_bool()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromMeta)]
+pub enum IndexKind {
+    /// A `HashMap` inside a `LazyLock`, which is created on the first access
+    #[darling(rename = "hash_map")]
+    HashMap,
+
+    /// A static slice sorted by a path, which is searched by binary search
+    #[darling(rename = "sorted")]
+    Sorted,
+}
+
+#[derive(Debug, derive_more::Display)]
+pub enum ParseIndexAttrError {
+    #[display("`kind = \"hash_map\"` requires the `std` feature")]
+    HashMapRequiresStd,
+}
+
+impl TryFrom<IndexAttr> for IndexSettings {
+    type Error = ParseIndexAttrError;
+
+    fn try_from(value: IndexAttr) -> Result<Self, Self::Error> {
+        let std = cfg!(feature = "std");
+        let kind = match value.kind {
+            Some(IndexKind::HashMap) if !std => {
+                return Err(ParseIndexAttrError::HashMapRequiresStd);
+            }
+            Some(kind) => kind,
+            None if std => IndexKind::HashMap,
+            None => IndexKind::Sorted,
+        };
+        Ok(Self {
+            kind,
+            case_insensitive: value.case_insensitive,
+            normalize_path: value.normalize_path,
+            percent_decode: value.percent_decode,
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum CaseInsensitive {
+    #[default]
+    No = 0,
+    Yes = 1,
+}
+
+impl BoolLikeEnum for CaseInsensitive {
+    fn yes() -> Self {
+        Self::Yes
+    }
+
+    fn no() -> Self {
+        Self::No
+    }
+}
+
+impl FromMeta for CaseInsensitive {
+    fn from_bool(value: bool) -> darling::Result<Self> {
+        Self::darling_from_bool(value)
+    }
+
+    fn from_char(value: char) -> darling::Result<Self> {
+        Self::darling_from_char(value)
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Self::darling_from_string(value)
+    }
+
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::Yes)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum NormalizePath {
+    #[default]
+    No = 0,
+    Yes = 1,
+}
+
+impl BoolLikeEnum for NormalizePath {
+    fn yes() -> Self {
+        Self::Yes
+    }
+
+    fn no() -> Self {
+        Self::No
+    }
+}
+
+impl FromMeta for NormalizePath {
+    fn from_bool(value: bool) -> darling::Result<Self> {
+        Self::darling_from_bool(value)
+    }
+
+    fn from_char(value: char) -> darling::Result<Self> {
+        Self::darling_from_char(value)
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Self::darling_from_string(value)
+    }
+
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::Yes)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PercentDecode {
+    #[default]
+    No = 0,
+    Yes = 1,
+}
+
+impl BoolLikeEnum for PercentDecode {
+    fn yes() -> Self {
+        Self::Yes
+    }
+
+    fn no() -> Self {
+        Self::No
+    }
+}
+
+impl FromMeta for PercentDecode {
+    fn from_bool(value: bool) -> darling::Result<Self> {
+        Self::darling_from_bool(value)
+    }
+
+    fn from_char(value: char) -> darling::Result<Self> {
+        Self::darling_from_char(value)
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Self::darling_from_string(value)
+    }
+
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::Yes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use darling::FromMeta;
+    use syn::parse_quote;
+
+    use super::{
+        CaseInsensitive, IndexAttr, IndexKind, IndexSettings, NormalizePath, PercentDecode,
+    };
+
+    #[test]
+    fn from_meta() {
+        let attr = IndexAttr::from_meta(&parse_quote!(index(kind = "sorted"))).unwrap();
+        assert_eq!(attr.kind, Some(IndexKind::Sorted));
+
+        let attr = IndexAttr::from_meta(&parse_quote!(index(kind = "hash_map"))).unwrap();
+        assert_eq!(attr.kind, Some(IndexKind::HashMap));
+
+        let attr = IndexAttr::from_meta(&parse_quote!(index())).unwrap();
+        assert_eq!(attr.kind, None);
+        assert_eq!(attr.case_insensitive, CaseInsensitive::No);
+        assert_eq!(attr.normalize_path, NormalizePath::No);
+        assert_eq!(attr.percent_decode, PercentDecode::No);
+    }
+
+    #[test]
+    fn from_meta_lookup_options() {
+        let attr = IndexAttr::from_meta(&parse_quote!(index(
+            case_insensitive,
+            normalize_path = true,
+            percent_decode
+        )))
+        .unwrap();
+        assert_eq!(attr.case_insensitive, CaseInsensitive::Yes);
+        assert_eq!(attr.normalize_path, NormalizePath::Yes);
+        assert_eq!(attr.percent_decode, PercentDecode::Yes);
+
+        let settings = IndexSettings::try_from(attr).unwrap();
+        assert!(!settings.is_exact());
+        assert_eq!(settings.key("Static/Main.CSS"), "static/main.css");
+
+        let settings = IndexSettings::try_from(IndexAttr::default()).unwrap();
+        assert!(settings.is_exact());
+        assert_eq!(settings.key("Static/Main.CSS"), "Static/Main.CSS");
+    }
+
+    #[test]
+    fn from_meta_unsupported_kind() {
+        let err = IndexAttr::from_meta(&parse_quote!(index(kind = "tree"))).unwrap_err();
+        assert!(format!("{err}").contains("tree"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn settings() {
+        let settings = IndexSettings::try_from(IndexAttr::default()).unwrap();
+        assert_eq!(settings.kind, IndexKind::HashMap);
+
+        let settings = IndexSettings::try_from(IndexAttr {
+            kind: Some(IndexKind::Sorted),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(settings.kind, IndexKind::Sorted);
+    }
+
+    #[test]
+    #[cfg(not(feature = "std"))]
+    fn settings_without_std() {
+        let settings = IndexSettings::try_from(IndexAttr::default()).unwrap();
+        assert_eq!(settings.kind, IndexKind::Sorted);
+
+        IndexSettings::try_from(IndexAttr {
+            kind: Some(IndexKind::HashMap),
+            ..Default::default()
+        })
+        .unwrap_err();
+    }
+}
@@ -0,0 +1,155 @@
+use darling::FromMeta;
+
+use crate::{embed::bool_like_enum::BoolLikeEnum, fs::EntryPath};
+
+use super::path_match::PathMatch;
+
+#[derive(Debug, Default, Clone, FromMeta)]
+pub struct MimeAttr {
+    /// Should the content of a file be inspected if its extension is unknown.
+    /// Default: no
+    #[darling(default)]
+    pub sniff: MimeSniff,
+
+    /// Explicit mime types for matched files.
+    /// They are checked in the declaration order and take precedence over the extension and the content
+    #[darling(default, multiple, rename = "map")]
+    pub mappings: Vec<MimeMapping>,
+}
+
+#[derive(Debug, Clone, FromMeta)]
+pub struct MimeMapping {
+    #[darling(flatten)]
+    pub matcher: PathMatch,
+
+    /// The mime type of a matched file
+    pub value: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct MimeSettings {
+    pub sniff: MimeSniff,
+    pub mappings: Vec<MimeMapping>,
+}
+
+impl MimeSettings {
+    /// The first explicit mime type, which matches the path
+    pub fn mapped(&self, path: &EntryPath) -> Option<&str> {
+        self.mappings
+            .iter()
+            .find(|m| m.matcher.is_match(path))
+            .map(|m| m.value.as_str())
+    }
+}
+
+impl From<MimeAttr> for MimeSettings {
+    fn from(value: MimeAttr) -> Self {
+        Self {
+            sniff: value.sniff,
+            mappings: value.mappings,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum MimeSniff {
+    #[default]
+    No = 0,
+    Yes = 1,
+}
+
+impl BoolLikeEnum for MimeSniff {
+    fn yes() -> Self {
+        Self::Yes
+    }
+
+    fn no() -> Self {
+        Self::No
+    }
+}
+
+impl FromMeta for MimeSniff {
+    fn from_bool(value: bool) -> darling::Result<Self> {
+        Self::darling_from_bool(value)
+    }
+
+    fn from_char(value: char) -> darling::Result<Self> {
+        Self::darling_from_char(value)
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Self::darling_from_string(value)
+    }
+
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::Yes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use darling::FromMeta;
+    use syn::parse_quote;
+
+    use crate::fs::{EntryIdent, EntryPath};
+
+    use super::{MimeAttr, MimeSettings, MimeSniff};
+
+    fn entry_path(relative: &str) -> EntryPath {
+        EntryPath {
+            origin: String::new(),
+            relative: relative.to_owned(),
+            ident: EntryIdent::root(parse_quote!(Assets)),
+            file_name: Default::default(),
+            file_stem: Default::default(),
+        }
+    }
+
+    #[test]
+    fn from_meta() {
+        let attr = MimeAttr::from_meta(&parse_quote!(mime(
+            sniff,
+            map(pattern = "*.tpl", value = "text/html"),
+            map(regex = "^raw/.*", value = "application/octet-stream"),
+        )))
+        .unwrap();
+        assert_eq!(attr.sniff, MimeSniff::Yes);
+        assert_eq!(attr.mappings.len(), 2);
+        assert_eq!(attr.mappings[0].value, "text/html");
+        assert!(attr.mappings[0].matcher.pattern().is_some());
+        assert!(attr.mappings[1].matcher.regex().is_some());
+
+        let attr = MimeAttr::from_meta(&parse_quote!(mime())).unwrap();
+        assert_eq!(attr.sniff, MimeSniff::No);
+        assert!(attr.mappings.is_empty());
+
+        let attr = MimeAttr::from_meta(&parse_quote!(mime(sniff = false))).unwrap();
+        assert_eq!(attr.sniff, MimeSniff::No);
+    }
+
+    #[test]
+    fn from_meta_mapping_without_value() {
+        MimeAttr::from_meta(&parse_quote!(mime(map(pattern = "*.tpl")))).unwrap_err();
+    }
+
+    #[test]
+    fn from_meta_unsupported_sniff() {
+        let err = MimeAttr::from_meta(&parse_quote!(mime(sniff = "always"))).unwrap_err();
+        assert!(format!("{err:?}").contains("always"));
+    }
+
+    #[test]
+    fn mapped() {
+        let settings = MimeSettings::from(
+            MimeAttr::from_meta(&parse_quote!(mime(
+                map(pattern = "*.tpl", value = "text/html"),
+                map(pattern = "*.t*", value = "text/plain"),
+            )))
+            .unwrap(),
+        );
+        assert_eq!(settings.mapped(&entry_path("a/b.tpl")), Some("text/html"));
+        assert_eq!(settings.mapped(&entry_path("a/b.txt")), Some("text/plain"));
+        assert_eq!(settings.mapped(&entry_path("a/b.html")), None);
+    }
+}
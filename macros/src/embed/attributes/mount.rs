@@ -0,0 +1,125 @@
+use darling::FromMeta;
+
+/// `mount(path = "sub", embed = crate::OtherAssets)`
+#[derive(Debug, Clone, FromMeta)]
+pub struct MountAttr {
+    /// A `/` separated path relative to the root, where the embed is mounted
+    path: String,
+
+    /// A path of the struct, which is derived with `definitions` of this embed
+    embed: syn::Path,
+}
+
+/// A separately derived embed, which is a dir of this one
+#[derive(Debug, Clone)]
+pub struct Mount {
+    /// The relative path of the parent dir, empty for the root
+    parent: String,
+
+    /// The name of the dir in the parent
+    name: String,
+
+    embed: syn::Path,
+}
+
+#[derive(Debug, derive_more::Display)]
+pub enum ParseMountAttrError {
+    #[display("the mount path `{_0}` must be a relative path without `.` and `..` segments")]
+    InvalidPath(String),
+
+    #[display("the path `{_0}` is mounted twice")]
+    Duplicate(String),
+}
+
+/// Splits a `/` separated relative path of a mount point into segments.
+/// Leading and trailing `/` are ignored, `None` if a segment is empty, `.`, `..` or contains `\\`
+pub fn mount_path_segments(path: &str) -> Option<Vec<&str>> {
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+    (!segments
+        .iter()
+        .any(|s| s.is_empty() || *s == "." || *s == ".." || s.contains('\\')))
+    .then_some(segments)
+}
+
+impl Mount {
+    pub fn parent(&self) -> &str {
+        &self.parent
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The relative path of the mount point
+    pub fn path(&self) -> String {
+        if self.parent.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}/{}", self.parent, self.name)
+        }
+    }
+
+    pub fn embed(&self) -> &syn::Path {
+        &self.embed
+    }
+
+    pub fn create(attrs: Vec<MountAttr>) -> Result<Vec<Self>, ParseMountAttrError> {
+        let mut mounts: Vec<Self> = Vec::with_capacity(attrs.len());
+        for MountAttr { path, embed } in attrs {
+            let Some(segments) = mount_path_segments(&path) else {
+                return Err(ParseMountAttrError::InvalidPath(path));
+            };
+            let (name, parent) = segments.split_last().expect("split is never empty");
+            let mount = Self {
+                parent: parent.join("/"),
+                name: (*name).to_owned(),
+                embed,
+            };
+            if mounts.iter().any(|m| m.path() == mount.path()) {
+                return Err(ParseMountAttrError::Duplicate(mount.path()));
+            }
+            mounts.push(mount);
+        }
+        Ok(mounts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use darling::FromMeta;
+    use syn::parse_quote;
+
+    use super::{Mount, MountAttr, ParseMountAttrError};
+
+    fn parse(path: &str) -> Result<Vec<Mount>, ParseMountAttrError> {
+        let meta: syn::Meta = parse_quote!(mount(path = #path, embed = crate::Other));
+        Mount::create(vec![MountAttr::from_meta(&meta).unwrap()])
+    }
+
+    #[test]
+    fn create() {
+        let mounts = parse("/a/b/sub/").unwrap();
+        assert_eq!(mounts[0].parent(), "a/b");
+        assert_eq!(mounts[0].name(), "sub");
+        assert_eq!(mounts[0].path(), "a/b/sub");
+
+        let mounts = parse("sub").unwrap();
+        assert_eq!(mounts[0].parent(), "");
+        assert_eq!(mounts[0].path(), "sub");
+
+        for path in ["", "/", "a//b", "a/./b", "../a", "a\\b"] {
+            assert!(
+                matches!(parse(path), Err(ParseMountAttrError::InvalidPath(_))),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn duplicate() {
+        let meta: syn::Meta = parse_quote!(mount(path = "a/sub", embed = crate::Other));
+        let attr = MountAttr::from_meta(&meta).unwrap();
+        let result = Mount::create(vec![attr.clone(), attr]);
+        assert!(matches!(result, Err(ParseMountAttrError::Duplicate(p)) if p == "a/sub"));
+    }
+}
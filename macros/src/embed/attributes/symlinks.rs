@@ -0,0 +1,47 @@
+use darling::FromMeta;
+
+/// How symlinks inside the embedded dir are handled
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, FromMeta)]
+pub enum Symlinks {
+    /// A symlink is embedded as its target. A symlink to one of its parent dirs is an error
+    #[default]
+    #[darling(rename = "follow")]
+    Follow,
+
+    /// Symlinks are not embedded
+    #[darling(rename = "skip")]
+    Skip,
+
+    /// A symlink is embedded as a file, which content is the target path
+    #[darling(rename = "preserve")]
+    Preserve,
+
+    /// A symlink is a compilation error
+    #[darling(rename = "error")]
+    Error,
+}
+
+#[cfg(test)]
+mod tests {
+    use darling::FromMeta;
+    use syn::parse_quote;
+
+    use super::Symlinks;
+
+    #[test]
+    fn from_meta() {
+        let cases = [
+            ("follow", Symlinks::Follow),
+            ("skip", Symlinks::Skip),
+            ("preserve", Symlinks::Preserve),
+            ("error", Symlinks::Error),
+        ];
+        for (value, expected) in cases {
+            let meta: syn::Meta = parse_quote!(symlinks = #value);
+            assert_eq!(Symlinks::from_meta(&meta).unwrap(), expected);
+        }
+
+        let meta: syn::Meta = parse_quote!(symlinks = "unknown");
+        assert!(Symlinks::from_meta(&meta).is_err());
+    }
+}
@@ -0,0 +1,111 @@
+use embed_it_utils::entry::EntryKind;
+use quote::quote;
+
+use crate::{
+    fs::EntryIdent,
+    utils::{entry_ext::EntryKindExt, unique_names::UniqueIdents},
+};
+
+use super::{IndexTokens, attributes::embed::GenerationSettings};
+
+/// Generates an enum with a variant per entry of the `kind` from the index of the root.
+/// The variants are named by the relative paths, e.g. `one_txt/hello` is `OneTxtHello`
+pub fn generate(
+    settings: &GenerationSettings,
+    kind: EntryKind,
+    enum_ident: &syn::Ident,
+    index: &[IndexTokens],
+) -> proc_macro2::TokenStream {
+    let mut idents = UniqueIdents::default();
+    let variants = index
+        .iter()
+        .filter(|tokens| tokens.kind == kind && !tokens.alias)
+        .map(|tokens| {
+            let ident = EntryIdent::create(&tokens.relative_path, &mut idents)
+                .expect("the relative path of a child is never empty");
+            (ident.struct_like().ident().clone(), tokens)
+        })
+        .collect::<Vec<_>>();
+
+    let entry_path = settings.entry_path(0);
+    let entry_struct_path = settings.entry_param_for(kind, 0);
+    let kind_ident = kind.ident();
+    let kind_name = match kind {
+        EntryKind::Dir => "dir",
+        EntryKind::File => "file",
+    };
+    let enum_doc = format!(
+        "All embedded {kind_name}s of [`{}`]",
+        settings.main_struct_ident
+    );
+
+    let definitions = variants.iter().map(|(ident, tokens)| {
+        let doc = format!("`{}`", tokens.relative_path);
+        quote! {
+            #[doc = #doc]
+            #ident,
+        }
+    });
+    let all = variants.iter().map(|(ident, _)| quote! { Self::#ident, });
+    let as_str = variants.iter().map(|(ident, tokens)| {
+        let relative_path = &tokens.relative_path;
+        quote! { Self::#ident => #relative_path, }
+    });
+    let entry = variants.iter().map(|(ident, tokens)| {
+        let struct_path = &tokens.struct_path;
+        quote! {
+            Self::#ident => ::embed_it::Entry::#kind_ident(#entry_struct_path(&#struct_path)),
+        }
+    });
+    let from_str = variants.iter().map(|(ident, tokens)| {
+        let relative_path = &tokens.relative_path;
+        quote! { #relative_path => Ok(Self::#ident), }
+    });
+
+    quote! {
+        #[doc = #enum_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum #enum_ident {
+            #(#definitions)*
+        }
+
+        #[automatically_derived]
+        impl #enum_ident {
+            /// All variants in the depth-first order of the entries
+            pub const ALL: &'static [Self] = &[#(#all)*];
+
+            /// The relative path of the entry
+            pub const fn as_str(&self) -> &'static str {
+                match *self {
+                    #(#as_str)*
+                }
+            }
+
+            /// The embedded entry
+            pub const fn entry(&self) -> #entry_path {
+                match *self {
+                    #(#entry)*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::str::FromStr for #enum_ident {
+            type Err = ::embed_it::UnknownEntry;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #(#from_str)*
+                    _ => Err(::embed_it::UnknownEntry),
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::convert::From<#enum_ident> for #entry_path {
+            fn from(value: #enum_ident) -> Self {
+                value.entry()
+            }
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use embed_it_utils::entry::EntryKind;
+use quote::quote;
+
+use super::{IndexTokens, attributes::embed::GenerationSettings};
+
+/// Generates `const ID: u32` for every file struct and the `FILES` table with `by_id` for the root struct.
+/// The id of a file is its position in the list of all files sorted by the relative path
+pub fn generate(settings: &GenerationSettings, index: &[IndexTokens]) -> proc_macro2::TokenStream {
+    let mut files = index
+        .iter()
+        .filter(|tokens| tokens.kind == EntryKind::File && !tokens.alias)
+        .collect::<Vec<_>>();
+    files.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+
+    let main_struct_ident = &settings.main_struct_ident;
+    let entry_path = settings.entry_path(0);
+    let file_struct_path = settings.file_entry_param(0);
+
+    let ids = files.iter().enumerate().map(|(id, tokens)| {
+        let id = u32::try_from(id).expect("the number of files fits into u32");
+        let struct_path = &tokens.struct_path;
+        quote! {
+            #[automatically_derived]
+            impl #struct_path {
+                /// The position of the file in the list of all files sorted by the relative path
+                pub const ID: u32 = #id;
+            }
+        }
+    });
+    let table = files.iter().map(|tokens| {
+        let struct_path = &tokens.struct_path;
+        quote! { #file_struct_path(&#struct_path), }
+    });
+
+    quote! {
+        #(#ids)*
+
+        #[automatically_derived]
+        impl #main_struct_ident {
+            /// All files sorted by the relative path, a file is at the position of its `ID`
+            pub const FILES: &'static [#file_struct_path] = &[#(#table)*];
+
+            /// The file with the `ID`
+            pub const fn by_id(id: u32) -> Option<#entry_path> {
+                let id = id as usize;
+                if id < Self::FILES.len() {
+                    Some(::embed_it::Entry::File(Self::FILES[id]))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
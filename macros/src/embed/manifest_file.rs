@@ -0,0 +1,96 @@
+use std::{io, path::Path};
+
+use embed_it_utils::entry::{Entry, EntryKind};
+use serde_json::{Map, Value, json};
+
+use crate::embedded_traits::{EmbeddedTrait, MakeEmbeddedTraitImplementationError, TraitAttr};
+
+use super::{EntryTokens, GenerateContext};
+
+/// A JSON description of an entry and its children, which is kept in the entry's items
+#[derive(Debug, Default)]
+pub struct ManifestRecord(pub Value);
+
+/// Describes the entry of the context: a relative path, generated idents, a size,
+/// derived traits and hashes, and the same for the children
+pub fn record(
+    ctx: &GenerateContext<'_>,
+    entries: &[EntryTokens],
+) -> Result<Value, MakeEmbeddedTraitImplementationError> {
+    let kind = ctx.entry.kind();
+    let traits = ctx
+        .settings
+        .trait_for(kind)
+        .map(
+            |d| d.embedded_traits().collect::<Vec<_>>(),
+            |f| f.embedded_traits().collect::<Vec<_>>(),
+        )
+        .value();
+
+    let mut hashes = Map::new();
+    for t in &traits {
+        if let Some(hash) = t.entry_hash(ctx, entries) {
+            let (name, hash) = hash?;
+            let hex = hash.iter().map(|b| format!("{b:02x}")).collect::<String>();
+            hashes.insert(name.to_string(), Value::String(hex));
+        }
+    }
+
+    // sorted, so the manifest does not depend on the order of derives
+    let mut trait_names = traits
+        .iter()
+        .map(|t| trait_name(*t, ctx))
+        .collect::<Vec<_>>();
+    trait_names.sort();
+
+    let path = ctx.entry_path();
+    let mut record = json!({
+        "kind": match kind {
+            EntryKind::Dir => "dir",
+            EntryKind::File => "file",
+        },
+        "path": path.relative,
+        "struct": ctx.entry_struct_ident().name(),
+        "field": ctx.entry_mod_ident().name(),
+        "traits": trait_names,
+        "hashes": hashes,
+    });
+
+    match &ctx.entry {
+        Entry::Dir(_) => {
+            let children = entries
+                .iter()
+                .filter_map(|e| e.items.get::<ManifestRecord>())
+                .map(|r| r.0.clone())
+                .collect::<Vec<_>>();
+            record["entries"] = Value::Array(children);
+        }
+        Entry::File(info) => {
+            record["size"] = json!(info.metadata().len());
+        }
+    }
+
+    Ok(record)
+}
+
+/// The name of the trait as it is visible for a user, e.g. `Sha2_256Hash`
+fn trait_name(t: &dyn EmbeddedTrait, ctx: &GenerateContext<'_>) -> String {
+    let path = t.path(0, ctx.settings);
+    path.segments
+        .last()
+        .map(|s| s.ident.to_string())
+        .unwrap_or_default()
+}
+
+/// Writes the manifest as pretty JSON. The file is not touched if its content is the same
+pub fn write(path: &Path, root: &Value) -> Result<(), io::Error> {
+    let content = serde_json::to_string_pretty(root)?;
+    if std::fs::read_to_string(path).is_ok_and(|prev| prev == content) {
+        return Ok(());
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, content)
+}
@@ -0,0 +1,175 @@
+use embed_it_utils::entry::EntryKind;
+use quote::{ToTokens, quote};
+
+use crate::utils::entry_ext::EntryKindExt;
+
+use super::{IndexTokens, attributes::embed::GenerationSettings};
+
+/// A position in the table: entries of the embed before it and tables of mounted embeds before it,
+/// whose lengths are known only to the compiler
+#[derive(Debug, Clone, Default)]
+struct Offset<'a> {
+    fixed: usize,
+    mounts: Vec<&'a syn::Path>,
+}
+
+impl ToTokens for Offset<'_> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self { fixed, mounts } = self;
+        tokens.extend(quote! { #fixed #(+ #mounts::WALK_ENTRIES.len())* });
+    }
+}
+
+/// Consecutive rows of the table, either generated or a table of a mounted embed
+enum Part<'a> {
+    Rows(Vec<&'a IndexTokens>),
+    Mount {
+        struct_path: &'a syn::Path,
+        depth: u16,
+    },
+}
+
+fn depth(tokens: &IndexTokens) -> u16 {
+    u16::try_from(tokens.relative_path.matches('/').count() + 1).expect("the depth fits into u16")
+}
+
+/// Generates the flattened table of all entries in depth-first order for the root struct
+/// and `WALK_START..WALK_END` bounds of the subtree for every dir struct.
+/// A subtree of a dir is contiguous in the depth-first order, so every dir slices the same table.
+/// A mounted embed is followed by its own table, so it must derive `Walk` as well.
+/// Depths are needed by `Walk` and paths are needed by `Find`, which doesn't support mounts
+pub fn generate(
+    settings: &GenerationSettings,
+    index: &[IndexTokens],
+    with_depths: bool,
+    with_paths: bool,
+) -> proc_macro2::TokenStream {
+    let rows = index.iter().filter(|i| !i.alias).collect::<Vec<_>>();
+
+    let mut parts = vec![Part::Rows(Vec::new())];
+    let mut offsets = Vec::with_capacity(rows.len());
+    let mut total = Offset::default();
+    for tokens in &rows {
+        offsets.push(total.clone());
+        total.fixed += 1;
+        if let Some(Part::Rows(part)) = parts.last_mut() {
+            part.push(tokens);
+        }
+        if tokens.mount {
+            total.mounts.push(&tokens.struct_path);
+            parts.push(Part::Mount {
+                struct_path: &tokens.struct_path,
+                depth: depth(tokens),
+            });
+            parts.push(Part::Rows(Vec::new()));
+        }
+    }
+
+    let main_struct_ident = &settings.main_struct_ident;
+    let entry_path = settings.entry_path(0);
+
+    let to_entry = |tokens: &&IndexTokens| {
+        let IndexTokens {
+            struct_path, kind, ..
+        } = tokens;
+        let kind_ident = kind.ident();
+        let entry_struct_path = settings.entry_param_for(*kind, 0);
+        quote! {
+            ::embed_it::Entry::#kind_ident(#entry_struct_path(&#struct_path)),
+        }
+    };
+    let to_depth = |tokens: &&IndexTokens| {
+        let depth = depth(tokens);
+        quote! { #depth, }
+    };
+
+    let entries = if total.mounts.is_empty() {
+        let entries = rows.iter().map(to_entry);
+        quote! { &[#(#entries)*] }
+    } else {
+        let parts = parts.iter().map(|part| match part {
+            Part::Rows(rows) => {
+                let entries = rows.iter().map(to_entry);
+                quote! { &[#(#entries)*], }
+            }
+            Part::Mount { struct_path, .. } => quote! { #struct_path::WALK_ENTRIES, },
+        });
+        quote! { &::embed_it::concat::<_, { #total }>(&[#(#parts)*]) }
+    };
+    let depths = with_depths.then(|| {
+        let depths = if total.mounts.is_empty() {
+            let depths = rows.iter().map(to_depth);
+            quote! { &[#(#depths)*] }
+        } else {
+            let parts = parts.iter().map(|part| match part {
+                Part::Rows(rows) => {
+                    let depths = rows.iter().map(to_depth);
+                    quote! { (&[#(#depths)*], 0), }
+                }
+                Part::Mount { struct_path, depth } => {
+                    quote! { (#struct_path::WALK_DEPTHS, #depth), }
+                }
+            });
+            quote! { &::embed_it::concat_depths::<{ #total }>(&[#(#parts)*]) }
+        };
+        quote! {
+            /// The depths of [`Self::WALK_ENTRIES`], direct children of the root have the depth 1
+            #[doc(hidden)]
+            pub const WALK_DEPTHS: &'static [u16] = #depths;
+        }
+    });
+    let paths = with_paths.then(|| {
+        let paths = rows.iter().map(|tokens| {
+            let path = &tokens.relative_path;
+            quote! { #path, }
+        });
+        quote! {
+            /// The paths of [`Self::WALK_ENTRIES`] relative to the root
+            #[doc(hidden)]
+            pub const WALK_PATHS: &'static [&'static str] = &[#(#paths)*];
+        }
+    });
+
+    let bounds = rows
+        .iter()
+        .enumerate()
+        .filter(|(_, tokens)| tokens.kind == EntryKind::Dir && !tokens.mount)
+        .map(|(position, tokens)| {
+            let prefix = format!("{}/", tokens.relative_path);
+            let mut start = offsets[position].clone();
+            start.fixed += 1;
+            let end = rows[position + 1..]
+                .iter()
+                .position(|row| !row.relative_path.starts_with(&prefix))
+                .map_or(&total, |len| &offsets[position + 1 + len]);
+            let struct_path = &tokens.struct_path;
+            quote! {
+                #[automatically_derived]
+                impl #struct_path {
+                    #[doc(hidden)]
+                    pub const WALK_START: usize = #start;
+                    #[doc(hidden)]
+                    pub const WALK_END: usize = #end;
+                }
+            }
+        });
+
+    quote! {
+        #(#bounds)*
+
+        #[automatically_derived]
+        impl #main_struct_ident {
+            #[doc(hidden)]
+            pub const WALK_START: usize = 0;
+            #[doc(hidden)]
+            pub const WALK_END: usize = #total;
+
+            /// All entries in depth-first order, a dir is followed by its subtree
+            #[doc(hidden)]
+            pub const WALK_ENTRIES: &'static [#entry_path] = #entries;
+
+            #depths
+            #paths
+        }
+    }
+}
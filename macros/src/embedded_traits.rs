@@ -3,12 +3,18 @@ pub mod content;
 pub mod debug;
 pub mod direct_child_count;
 pub mod entries;
+pub mod find;
+pub mod hashed_name;
 pub mod hashes;
 pub mod index;
+pub mod link_target;
+pub mod manifest;
 pub mod meta;
+pub mod mime;
 pub mod path;
 pub mod recursive_child_count;
 pub mod str_content;
+pub mod walk;
 
 use std::{
     borrow::Cow,
@@ -46,9 +52,15 @@ impl Default for AllEmbededTraits {
         map.add(&entries::EntriesTrait);
         map.add(&index::IndexTrait);
         map.add(&meta::MetaTrait);
+        map.add(&manifest::ManifestTrait);
+        map.add(&mime::MimeTrait);
+        map.add(&link_target::LinkTargetTrait);
+        map.add(&hashed_name::HashedNameTrait);
         map.add(&path::PathTrait);
         map.add(&direct_child_count::DirectChildCountTrait);
         map.add(&recursive_child_count::RecursiveChildCountTrait);
+        map.add(&walk::WalkTrait);
+        map.add(&find::FindTrait);
 
         #[cfg(feature = "md5")]
         map.add(hashes::md5::MD5);
@@ -62,6 +74,9 @@ impl Default for AllEmbededTraits {
             map.add(hashes::sha2::SHA2_256);
             map.add(hashes::sha2::SHA2_384);
             map.add(hashes::sha2::SHA2_512);
+            map.add(hashes::sha2::SRI_SHA2_256);
+            map.add(hashes::sha2::SRI_SHA2_384);
+            map.add(hashes::sha2::SRI_SHA2_512);
         }
 
         #[cfg(feature = "sha3")]
@@ -175,6 +190,15 @@ pub trait EmbeddedTrait: Send + Sync + Debug {
         index: &[IndexTokens],
     ) -> Option<Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError>>;
 
+    /// Returns the method name and the hash of the entry if it is a hash trait, see [`manifest::ManifestTrait`]
+    fn entry_hash(
+        &self,
+        _ctx: &GenerateContext<'_>,
+        _entries: &[EntryTokens],
+    ) -> Option<Result<(Ident, Vec<u8>), MakeEmbeddedTraitImplementationError>> {
+        None
+    }
+
     fn implementation(
         &self,
         ctx: &mut GenerateContext<'_>,
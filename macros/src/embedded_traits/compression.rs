@@ -1,4 +1,5 @@
 pub mod ids;
+pub mod stored;
 
 #[cfg(feature = "any-compression")]
 pub use internal::*;
@@ -7,20 +7,30 @@ pub mod zstd;
 #[cfg(feature = "brotli")]
 pub mod brotli;
 
-use std::{fmt::Debug, fs::OpenOptions, io::BufReader};
+use std::fmt::Debug;
 
 use embed_it_utils::entry::EntryKind;
 use quote::quote;
 
-use crate::embed::attributes::embed::GenerationSettings;
+use crate::embed::attributes::{
+    compression::{CompressionKind, CompressionSettings},
+    embed::GenerationSettings,
+};
 
 use crate::embedded_traits::{EmbeddedTrait, MakeEmbeddedTraitImplementationError};
+use crate::fs::FsInfo;
+
+use super::stored::{StoredContent, stored_content};
 
 pub trait CompressionAlg: Send + Sync {
     fn id(&self) -> &'static str;
+    fn kind(&self) -> CompressionKind;
     fn trait_path(&self) -> syn::Path;
+
+    /// The trait, which is used if the content is stored only when it is worth it
+    fn optional_trait_path(&self) -> syn::Path;
     fn trait_method(&self) -> syn::Ident;
-    fn make_compressor(&self) -> impl Compressor;
+    fn make_compressor(&self, settings: &CompressionSettings) -> std::io::Result<impl Compressor>;
 }
 
 pub trait Compressor: std::io::Write {
@@ -43,6 +53,45 @@ impl<T: CompressionAlg + Debug> CompressionTrait<T> {
     }
 }
 
+/// Compresses the file and returns the original size and the compressed content
+pub fn compress_file(
+    alg: &impl CompressionAlg,
+    info: &FsInfo,
+    settings: &CompressionSettings,
+) -> Result<(usize, Vec<u8>), MakeEmbeddedTraitImplementationError> {
+    let file_path = info.path().origin_path();
+    let mut reader = info.open().map_err(|e| {
+        MakeEmbeddedTraitImplementationError::with_error(
+            format!("Unable to open file {file_path:?}"),
+            e,
+        )
+    })?;
+    let mut compressor = alg.make_compressor(settings).map_err(|e| {
+        MakeEmbeddedTraitImplementationError::with_error(
+            format!("Unable to create '{}' compressor", alg.id()),
+            e,
+        )
+    })?;
+
+    let original_len = std::io::copy(&mut reader, &mut compressor).map_err(|e| {
+        MakeEmbeddedTraitImplementationError::with_error(
+            format!(
+                "Unable to compress content of {file_path:?} with '{}'",
+                alg.id()
+            ),
+            e,
+        )
+    })?;
+
+    let content = compressor.finalize().map_err(|e| {
+        MakeEmbeddedTraitImplementationError::with_error(
+            format!("Unable to compress file {file_path:?} with {}", alg.id()),
+            e,
+        )
+    })?;
+    Ok((original_len as usize, content))
+}
+
 impl<T: CompressionAlg> CompressionTrait<T> {
     fn impl_body(
         &self,
@@ -54,49 +103,55 @@ impl<T: CompressionAlg> CompressionTrait<T> {
                 trait_id: self.0.id(),
             });
         }
-        let file_path = ctx.entry.as_ref().value().path().origin_path();
-        let file = OpenOptions::new()
-            .read(true)
-            .create(false)
-            .create_new(false)
-            .append(false)
-            .write(false)
-            .truncate(false)
-            .open(file_path)
-            .map_err(|e| {
-                MakeEmbeddedTraitImplementationError::with_error(
-                    format!("Unable to open file {file_path:?}"),
-                    e,
-                )
-            })?;
-        let mut reader = BufReader::new(file);
-        let mut compressor = self.0.make_compressor();
-
-        std::io::copy(&mut reader, &mut compressor).map_err(|e| {
-            MakeEmbeddedTraitImplementationError::with_error(
-                format!(
-                    "Unable to compress content of {file_path:?} with '{}'",
-                    self.0.id()
-                ),
-                e,
-            )
-        })?;
-
-        let content = compressor.finalize().map_err(|e| {
-            MakeEmbeddedTraitImplementationError::with_error(
-                format!("Unable to compress file {file_path:?} with {}", self.0.id()),
-                e,
-            )
-        })?;
+
+        let settings = ctx.settings.file.compression();
         let method = self.0.trait_method();
-        let res = quote! {
-            pub fn #method(&self) -> &'static [u8] {
-                const VALUE: &[u8] = &[#(#content),*];
-                VALUE
+        let optional = settings.min_ratio.is_some();
+
+        // the content is already embedded in the compressed form, so it is reused
+        if settings.store_compressed == Some(self.0.kind()) {
+            if let Some(StoredContent { value, definition }) = stored_content(ctx)? {
+                let value = if optional {
+                    quote! { Some(#value.compressed()) }
+                } else {
+                    quote! { #value.compressed() }
+                };
+                let ty = if optional {
+                    quote! { Option<&'static [u8]> }
+                } else {
+                    quote! { &'static [u8] }
+                };
+                return Ok(quote! {
+                    #definition
+
+                    pub fn #method(&self) -> #ty {
+                        #value
+                    }
+                });
             }
-        };
+        }
 
-        Ok(res)
+        let (original_len, content) = compress_file(&self.0, ctx.entry_info(), settings)?;
+        if !optional {
+            return Ok(quote! {
+                pub fn #method(&self) -> &'static [u8] {
+                    const VALUE: &[u8] = &[#(#content),*];
+                    VALUE
+                }
+            });
+        }
+
+        let value = if settings.is_worth(original_len, content.len()) {
+            quote! { Some(&[#(#content),*]) }
+        } else {
+            quote! { None }
+        };
+        Ok(quote! {
+            pub fn #method(&self) -> Option<&'static [u8]> {
+                const VALUE: Option<&[u8]> = #value;
+                VALUE
+            }
+        })
     }
 }
 
@@ -105,8 +160,12 @@ impl<T: CompressionAlg + Debug> EmbeddedTrait for CompressionTrait<T> {
         self.0.id()
     }
 
-    fn path(&self, _: usize, _: &GenerationSettings) -> syn::Path {
-        self.0.trait_path()
+    fn path(&self, _: usize, settings: &GenerationSettings) -> syn::Path {
+        if settings.file.compression().min_ratio.is_some() {
+            self.0.optional_trait_path()
+        } else {
+            self.0.trait_path()
+        }
     }
 
     fn definition(&self, _: &GenerationSettings) -> Option<proc_macro2::TokenStream> {
@@ -124,14 +183,22 @@ impl<T: CompressionAlg + Debug> EmbeddedTrait for CompressionTrait<T> {
 
     fn impl_trait_body(
         &self,
-        _ctx: &mut crate::embed::GenerateContext<'_>,
+        ctx: &mut crate::embed::GenerateContext<'_>,
         _entries: &[crate::embed::EntryTokens],
         _index: &[crate::embed::IndexTokens],
     ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
         let method = self.0.trait_method();
-        let res = quote! {
-            fn #method(&self) -> &'static [u8] {
-                self.#method()
+        let res = if ctx.settings.file.compression().min_ratio.is_some() {
+            quote! {
+                fn #method(&self) -> Option<&'static [u8]> {
+                    self.#method()
+                }
+            }
+        } else {
+            quote! {
+                fn #method(&self) -> &'static [u8] {
+                    self.#method()
+                }
             }
         };
         Ok(res)
@@ -1,5 +1,6 @@
 use syn::parse_quote;
 
+use crate::embed::attributes::compression::{CompressionKind, CompressionSettings};
 use crate::embedded_traits::compression::ids;
 
 use super::{CompressionAlg, CompressionTrait, Compressor, FinalizeCompressorError};
@@ -18,16 +19,30 @@ impl CompressionAlg for Brotli {
         ids::BROTLI.id
     }
 
+    fn kind(&self) -> CompressionKind {
+        CompressionKind::Brotli
+    }
+
     fn trait_path(&self) -> syn::Path {
         parse_quote!(::embed_it::BrotliContent)
     }
 
+    fn optional_trait_path(&self) -> syn::Path {
+        parse_quote!(::embed_it::MaybeBrotliContent)
+    }
+
     fn trait_method(&self) -> syn::Ident {
         parse_quote!(brotli_content)
     }
 
-    fn make_compressor(&self) -> impl Compressor {
-        brotli::CompressorWriter::new(Vec::new(), 1024 * 1024, 11, 22)
+    fn make_compressor(&self, settings: &CompressionSettings) -> std::io::Result<impl Compressor> {
+        let settings = &settings.brotli;
+        Ok(brotli::CompressorWriter::new(
+            Vec::new(),
+            1024 * 1024,
+            settings.quality,
+            settings.window,
+        ))
     }
 }
 
@@ -44,7 +59,8 @@ mod tests {
 
     #[test]
     fn check() {
-        let mut compressor = Brotli.make_compressor();
+        let settings = CompressionSettings::default();
+        let mut compressor = Brotli.make_compressor(&settings).unwrap();
         let source = b"00000000000000000000000000000000";
         compressor.write_all(source).unwrap();
         let result = compressor.finalize().unwrap();
@@ -1,6 +1,7 @@
 use flate2::{Compression, write::GzEncoder};
 use syn::parse_quote;
 
+use crate::embed::attributes::compression::{CompressionKind, CompressionSettings};
 use crate::embedded_traits::compression::ids;
 
 use super::{CompressionAlg, CompressionTrait, Compressor, FinalizeCompressorError};
@@ -19,16 +20,27 @@ impl CompressionAlg for Gzip {
         ids::GZIP.id
     }
 
+    fn kind(&self) -> CompressionKind {
+        CompressionKind::Gzip
+    }
+
     fn trait_path(&self) -> syn::Path {
         parse_quote!(::embed_it::GzipContent)
     }
 
+    fn optional_trait_path(&self) -> syn::Path {
+        parse_quote!(::embed_it::MaybeGzipContent)
+    }
+
     fn trait_method(&self) -> syn::Ident {
         parse_quote!(gzip_content)
     }
 
-    fn make_compressor(&self) -> impl super::Compressor {
-        GzEncoder::new(Vec::new(), Compression::new(9))
+    fn make_compressor(&self, settings: &CompressionSettings) -> std::io::Result<impl Compressor> {
+        Ok(GzEncoder::new(
+            Vec::new(),
+            Compression::new(settings.gzip.level),
+        ))
     }
 }
 
@@ -42,11 +54,15 @@ mod tests {
     use hex_literal::hex;
     use pretty_assertions::assert_eq;
 
-    use crate::embedded_traits::compression::{CompressionAlg, Compressor, gzip::Gzip};
+    use crate::{
+        embed::attributes::compression::CompressionSettings,
+        embedded_traits::compression::{CompressionAlg, Compressor, gzip::Gzip},
+    };
 
     #[test]
     fn check() {
-        let mut compressor = Gzip.make_compressor();
+        let settings = CompressionSettings::default();
+        let mut compressor = Gzip.make_compressor(&settings).unwrap();
         let source = b"00000000000000000000000000000000";
         compressor.write_all(source).unwrap();
         let result = compressor.finalize().unwrap();
@@ -1,5 +1,9 @@
 use syn::parse_quote;
 
+use crate::embed::{
+    attributes::compression::{CompressionKind, CompressionSettings},
+    bool_like_enum::BoolLikeEnum,
+};
 use crate::embedded_traits::compression::ids;
 
 use super::{CompressionAlg, CompressionTrait, Compressor, FinalizeCompressorError};
@@ -18,16 +22,30 @@ impl CompressionAlg for Zstd {
         ids::ZSTD.id
     }
 
+    fn kind(&self) -> CompressionKind {
+        CompressionKind::Zstd
+    }
+
     fn trait_path(&self) -> syn::Path {
         parse_quote!(::embed_it::ZstdContent)
     }
 
+    fn optional_trait_path(&self) -> syn::Path {
+        parse_quote!(::embed_it::MaybeZstdContent)
+    }
+
     fn trait_method(&self) -> syn::Ident {
         parse_quote!(zstd_content)
     }
 
-    fn make_compressor(&self) -> impl Compressor {
-        zstd::stream::Encoder::new(Vec::new(), 22).unwrap()
+    fn make_compressor(&self, settings: &CompressionSettings) -> std::io::Result<impl Compressor> {
+        let settings = &settings.zstd;
+        let mut encoder = zstd::stream::Encoder::new(Vec::new(), settings.level)?;
+        encoder.long_distance_matching(settings.long_distance_matching.as_bool())?;
+        if let Some(window_log) = settings.window_log {
+            encoder.window_log(window_log)?;
+        }
+        Ok(encoder)
     }
 }
 pub const ZSTD: &CompressionTrait<Zstd> = &CompressionTrait::new(Zstd);
@@ -43,7 +61,8 @@ mod tests {
 
     #[test]
     fn check() {
-        let mut compressor = Zstd.make_compressor();
+        let settings = CompressionSettings::default();
+        let mut compressor = Zstd.make_compressor(&settings).unwrap();
         let source = b"00000000000000000000000000000000";
         compressor.write_all(source).unwrap();
         let result = compressor.finalize().unwrap();
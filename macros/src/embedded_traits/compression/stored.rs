@@ -0,0 +1,115 @@
+use quote::quote;
+
+use crate::{
+    embed::{
+        GenerateContext,
+        attributes::compression::{CompressionKind, CompressionSettings},
+    },
+    embedded_traits::MakeEmbeddedTraitImplementationError,
+    fs::FsInfo,
+};
+
+/// The content of a file, which is embedded only in the compressed form
+/// because of `compression(store_compressed = "..")`
+pub struct StoredContent {
+    /// An expression of type `&'static ::embed_it::CompressedContent`
+    pub value: proc_macro2::TokenStream,
+
+    /// The accessor of the compressed content. It is not empty only for the first
+    /// trait of an entry, because the accessor must be defined once
+    pub definition: proc_macro2::TokenStream,
+}
+
+#[derive(Debug, Default)]
+enum StoredContentState {
+    #[default]
+    Unknown,
+    Defined,
+    NotWorth,
+}
+
+/// Returns the compressed content of the current file or `None` if the content must be embedded as is
+pub fn stored_content(
+    ctx: &mut GenerateContext<'_>,
+) -> Result<Option<StoredContent>, MakeEmbeddedTraitImplementationError> {
+    let settings = ctx.settings.file.compression();
+    let Some(kind) = settings.store_compressed else {
+        return Ok(None);
+    };
+
+    let value = quote! { self.stored_content() };
+    match ctx.items.get::<StoredContentState>() {
+        Some(StoredContentState::Defined) => {
+            return Ok(Some(StoredContent {
+                value,
+                definition: quote! {},
+            }));
+        }
+        Some(StoredContentState::NotWorth) => return Ok(None),
+        Some(StoredContentState::Unknown) | None => {}
+    }
+
+    let (original_len, compressed) = compress(kind, ctx.entry_info(), settings)?;
+    if !settings.is_worth(original_len, compressed.len()) {
+        *ctx.items.get_or_default() = StoredContentState::NotWorth;
+        return Ok(None);
+    }
+    *ctx.items.get_or_default() = StoredContentState::Defined;
+
+    // the file is not included as is, but it must stay a dependency to be recompressed on changes.
+    // `dev_mode` reads the content at runtime, so there the file must not force recompilation
+    let tracking = ctx.entry_info().inline_content().is_none().then(|| {
+        let origin = &ctx.entry_path().origin;
+        let tracking = quote! {
+            const _: &[u8] = include_bytes!(#origin);
+        };
+        match &ctx.settings.dev_mode {
+            Some(dev_mode) => dev_mode.embedded_only(tracking),
+            None => tracking,
+        }
+    });
+    let compression = match kind {
+        CompressionKind::Zstd => quote! { ::embed_it::Compression::Zstd },
+        CompressionKind::Gzip => quote! { ::embed_it::Compression::Gzip },
+        CompressionKind::Brotli => quote! { ::embed_it::Compression::Brotli },
+    };
+    Ok(Some(StoredContent {
+        value,
+        definition: quote! {
+            #[doc(hidden)]
+            pub fn stored_content(&self) -> &'static ::embed_it::CompressedContent {
+                #tracking
+                static VALUE: ::embed_it::CompressedContent = ::embed_it::CompressedContent::new(
+                    #compression,
+                    &[#(#compressed),*],
+                );
+                &VALUE
+            }
+        },
+    }))
+}
+
+#[allow(unused_variables)]
+fn compress(
+    kind: CompressionKind,
+    info: &FsInfo,
+    settings: &CompressionSettings,
+) -> Result<(usize, Vec<u8>), MakeEmbeddedTraitImplementationError> {
+    match kind {
+        #[cfg(feature = "zstd")]
+        CompressionKind::Zstd => super::compress_file(&super::zstd::Zstd, info, settings),
+
+        #[cfg(feature = "gzip")]
+        CompressionKind::Gzip => super::compress_file(&super::gzip::Gzip, info, settings),
+
+        #[cfg(feature = "brotli")]
+        CompressionKind::Brotli => super::compress_file(&super::brotli::Brotli, info, settings),
+
+        // the feature is checked while parsing the attribute
+        #[allow(unreachable_patterns)]
+        _ => Err(MakeEmbeddedTraitImplementationError::Custom(
+            format!("`{}` compression is disabled", kind.id()).into(),
+            None,
+        )),
+    }
+}
@@ -4,7 +4,10 @@ use syn::parse_quote;
 
 use crate::{
     embed::{EntryTokens, GenerateContext, IndexTokens, attributes::embed::GenerationSettings},
-    embedded_traits::EmbeddedTrait,
+    embedded_traits::{
+        EmbeddedTrait,
+        compression::stored::{StoredContent, stored_content},
+    },
 };
 
 use super::MakeEmbeddedTraitImplementationError;
@@ -12,6 +15,48 @@ use super::MakeEmbeddedTraitImplementationError;
 #[derive(Debug)]
 pub struct ContentTrait;
 
+/// The accessor of the content read from the disk is defined for the current file
+#[derive(Debug, Default)]
+struct DiskContentDefined;
+
+/// Selects the `dev` accessor, which reads the content with `self.disk_content()`, if the `dev_mode` predicate is true
+/// and the `embedded` one otherwise. `Content` and `StrContent` of a file share one [`embed_it::DiskContent`],
+/// so a change is read and leaked once
+pub fn select_disk_content(
+    ctx: &mut GenerateContext<'_>,
+    dev: proc_macro2::TokenStream,
+    embedded: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let settings = ctx.settings;
+    // a preserved symlink or an archived file has nothing to read from the disk
+    let Some(dev_mode) = settings
+        .dev_mode
+        .as_ref()
+        .filter(|_| ctx.entry_info().inline_content().is_none())
+    else {
+        return embedded;
+    };
+
+    let definition = if ctx.items.get::<DiskContentDefined>().is_none() {
+        ctx.items.get_or_default::<DiskContentDefined>();
+        let origin = &ctx.entry_path().origin;
+        dev_mode.dev_only(quote! {
+            #[doc(hidden)]
+            pub fn disk_content(&self) -> &'static ::embed_it::DiskContent {
+                static VALUE: ::embed_it::DiskContent = ::embed_it::DiskContent::new(#origin);
+                &VALUE
+            }
+        })
+    } else {
+        quote! {}
+    };
+    let selected = dev_mode.select(dev, embedded);
+    quote! {
+        #definition
+        #selected
+    }
+}
+
 impl ContentTrait {
     fn impl_body(
         &self,
@@ -24,12 +69,51 @@ impl ContentTrait {
             });
         }
 
-        let origin = &ctx.entry.as_ref().value().path().origin;
-        Ok(quote! {
-            pub fn content(&self) -> &'static [u8] {
-                const VALUE: &[u8] = include_bytes!(#origin);
-                VALUE
+        let (definition, embedded) = match stored_content(ctx)? {
+            Some(StoredContent { value, definition }) => (
+                definition,
+                quote! {
+                    pub fn content(&self) -> &'static [u8] {
+                        #value.content()
+                    }
+                },
+            ),
+            None => {
+                let value = match ctx.entry_info().inline_content() {
+                    Some(content) => {
+                        let content = proc_macro2::Literal::byte_string(content);
+                        quote! { #content }
+                    }
+                    None => {
+                        let origin = &ctx.entry.as_ref().value().path().origin;
+                        quote! { include_bytes!(#origin) }
+                    }
+                };
+                (
+                    quote! {},
+                    quote! {
+                        pub fn content(&self) -> &'static [u8] {
+                            const VALUE: &[u8] = #value;
+                            VALUE
+                        }
+                    },
+                )
             }
+        };
+
+        let content = select_disk_content(
+            ctx,
+            quote! {
+                pub fn content(&self) -> &'static [u8] {
+                    self.disk_content().content()
+                }
+            },
+            embedded,
+        );
+
+        Ok(quote! {
+            #definition
+            #content
         })
     }
 }
@@ -13,7 +13,7 @@ pub struct DebugTrait;
 
 impl EmbeddedTrait for DebugTrait {
     fn path(&self, _nesting: usize, _: &GenerationSettings) -> syn::Path {
-        parse_quote!(::std::fmt::Debug)
+        parse_quote!(::core::fmt::Debug)
     }
 
     fn impl_body(
@@ -41,7 +41,7 @@ impl EmbeddedTrait for DebugTrait {
     ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
         match ctx.entry.kind() {
             EntryKind::Dir => {
-                let fields = entries.iter().fold(quote! {}, |mut accum, entry| {
+                let mut fields = entries.iter().fold(quote! {}, |mut accum, entry| {
                     let field_name = entry.field.name();
                     let field_ident = entry.field.ident();
                     if ctx.is_trait_implemented_for(entry.entry.kind(), &DebugTrait) {
@@ -56,6 +56,15 @@ impl EmbeddedTrait for DebugTrait {
                     }
                     accum
                 });
+                if ctx.is_trait_implemented_for(EntryKind::Dir, &DebugTrait) {
+                    for mount in &ctx.mounts {
+                        let field_name = mount.field.name();
+                        let field_ident = mount.field.ident();
+                        fields.extend(quote! {
+                            debug.field(#field_name, &self.#field_ident());
+                        });
+                    }
+                }
                 Ok(debug(ctx, fields))
             }
             EntryKind::File => {
@@ -76,7 +85,7 @@ fn debug(ctx: &GenerateContext<'_>, fields: proc_macro2::TokenStream) -> proc_ma
     let struct_name = ctx.entry_struct_ident().name();
 
     quote! {
-        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
             let mut debug = f.debug_struct(#struct_name);
             #fields
             debug.finish()
@@ -29,7 +29,7 @@ impl DirectChildCountTrait {
             });
         }
         let method = method();
-        let len = entries.len();
+        let len = entries.len() + ctx.mounts.len();
         Ok(quote! {
             pub fn #method(&self) -> usize {
                 #len
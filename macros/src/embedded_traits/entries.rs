@@ -3,7 +3,10 @@ use quote::quote;
 use syn::parse_quote;
 
 use crate::{
-    embed::{EntryTokens, GenerateContext, IndexTokens, attributes::embed::GenerationSettings},
+    embed::{
+        EntryTokens, GenerateContext, IndexTokens, MountTokens,
+        attributes::embed::GenerationSettings,
+    },
     embedded_traits::MakeEmbeddedTraitImplementationError,
     utils::entry_ext::EntryKindExt,
 };
@@ -38,11 +41,19 @@ impl EntriesTrait {
 
             entries
         });
+        // mounted embeds go after the own entries
+        let dir_struct_path = ctx.settings.dir_entry_param(ctx.level);
+        let mounts = ctx.mounts.iter().map(|MountTokens { struct_path, .. }| {
+            quote! {
+                ::embed_it::Entry::Dir(#dir_struct_path(&#struct_path)),
+            }
+        });
 
         Ok(quote! {
             pub fn entries(&self) -> &'static [#entry_path] {
                 const VALUE: &[#entry_path] = &[
                     #entries
+                    #(#mounts)*
                 ];
                 VALUE
             }
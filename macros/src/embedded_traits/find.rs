@@ -0,0 +1,88 @@
+use embed_it_utils::entry::EntryKind;
+use quote::quote;
+use syn::parse_quote;
+
+use crate::{
+    embed::{EntryTokens, GenerateContext, IndexTokens, attributes::embed::GenerationSettings},
+    embedded_traits::MakeEmbeddedTraitImplementationError,
+};
+
+use super::EmbeddedTrait;
+
+#[derive(Debug)]
+pub struct FindTrait;
+
+impl FindTrait {
+    fn impl_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
+        if ctx.entry.kind() != EntryKind::Dir {
+            return Err(MakeEmbeddedTraitImplementationError::UnsupportedEntry {
+                entry: ctx.entry.kind(),
+                trait_id: self.id(),
+            });
+        }
+        let dir = ctx.settings.dir_entry_param(ctx.level);
+        let file = ctx.settings.file_entry_param(ctx.level);
+
+        // the subtree of the dir is a slice of the table of the root, see `embed::walk_table`.
+        // Paths in the table are relative to the root, the path of a dir precedes its subtree
+        let root = ctx.make_level_path(ctx.settings.main_struct_ident.clone());
+        let prefix_len = if ctx.level == 0 {
+            quote! { 0 }
+        } else {
+            quote! { #root::WALK_PATHS[Self::WALK_START - 1].len() + 1 }
+        };
+
+        Ok(quote! {
+            pub fn relative_entries(&self) -> ::embed_it::RelativeEntries<#dir, #file> {
+                ::embed_it::RelativeEntries::new(
+                    &#root::WALK_PATHS[Self::WALK_START..Self::WALK_END],
+                    &#root::WALK_ENTRIES[Self::WALK_START..Self::WALK_END],
+                    #prefix_len,
+                )
+            }
+        })
+    }
+}
+
+impl EmbeddedTrait for FindTrait {
+    fn path(&self, level: usize, settings: &GenerationSettings) -> syn::Path {
+        let dir = settings.dir_entry_param(level);
+        let file = settings.file_entry_param(level);
+        parse_quote!(::embed_it::Find<#dir, #file>)
+    }
+
+    fn impl_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+        _entries: &[EntryTokens],
+        _index: &[IndexTokens],
+    ) -> Option<Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError>> {
+        Some(self.impl_body(ctx))
+    }
+
+    fn definition(&self, _: &GenerationSettings) -> Option<proc_macro2::TokenStream> {
+        None
+    }
+
+    fn id(&self) -> &'static str {
+        "Find"
+    }
+
+    fn impl_trait_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+        _: &[EntryTokens],
+        _: &[IndexTokens],
+    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
+        let dir = ctx.settings.dir_entry_param(ctx.level);
+        let file = ctx.settings.file_entry_param(ctx.level);
+        Ok(quote! {
+            fn relative_entries(&self) -> ::embed_it::RelativeEntries<#dir, #file> {
+                self.relative_entries()
+            }
+        })
+    }
+}
@@ -0,0 +1,145 @@
+use embed_it_utils::entry::EntryKind;
+use quote::quote;
+use syn::parse_quote;
+
+use crate::{
+    embed::{
+        EntryTokens, GenerateContext, IndexAliases, IndexTokens,
+        attributes::{embed::GenerationSettings, hashed_name::HashedNameHash},
+    },
+    embedded_traits::EmbeddedTrait,
+};
+
+use super::MakeEmbeddedTraitImplementationError;
+
+#[derive(Debug)]
+pub struct HashedNameTrait;
+
+impl HashedNameTrait {
+    fn impl_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
+        if ctx.entry.kind() != EntryKind::File {
+            return Err(MakeEmbeddedTraitImplementationError::UnsupportedEntry {
+                entry: ctx.entry.kind(),
+                trait_id: self.id(),
+            });
+        }
+
+        let settings = ctx.settings.file.hashed_name();
+        let hash = file_hash(settings.hash, ctx)?;
+        let path = ctx.entry.as_ref().value().path();
+        let hashed_name = settings.hashed_file_name(&path.file_name, &hash);
+        let relative = ctx.settings.mounted_path(&path.relative);
+        let hashed_path = match relative.strip_suffix(&path.file_name) {
+            Some(parent) => format!("{parent}{hashed_name}"),
+            None => hashed_name.clone(),
+        };
+
+        // indexes of the parents resolve the hashed name as well as the original one
+        ctx.items
+            .get_or_default::<IndexAliases>()
+            .0
+            .push(hashed_name);
+
+        Ok(quote! {
+            pub fn hashed_path(&self) -> &'static str {
+                const VALUE: &str = #hashed_path;
+                VALUE
+            }
+        })
+    }
+}
+
+#[allow(unused_variables)]
+fn file_hash(
+    hash: HashedNameHash,
+    ctx: &GenerateContext<'_>,
+) -> Result<Vec<u8>, MakeEmbeddedTraitImplementationError> {
+    #[allow(unused_imports)]
+    use super::hashes;
+
+    match hash {
+        #[cfg(feature = "md5")]
+        HashedNameHash::Md5 => hashes::md5::MD5.file_hash(ctx),
+
+        #[cfg(feature = "sha1")]
+        HashedNameHash::Sha1 => hashes::sha1::SHA1.file_hash(ctx),
+
+        #[cfg(feature = "sha2")]
+        HashedNameHash::Sha2_224 => hashes::sha2::SHA2_224.file_hash(ctx),
+
+        #[cfg(feature = "sha2")]
+        HashedNameHash::Sha2_256 => hashes::sha2::SHA2_256.file_hash(ctx),
+
+        #[cfg(feature = "sha2")]
+        HashedNameHash::Sha2_384 => hashes::sha2::SHA2_384.file_hash(ctx),
+
+        #[cfg(feature = "sha2")]
+        HashedNameHash::Sha2_512 => hashes::sha2::SHA2_512.file_hash(ctx),
+
+        #[cfg(feature = "sha3")]
+        HashedNameHash::Sha3_224 => hashes::sha3::SHA3_224.file_hash(ctx),
+
+        #[cfg(feature = "sha3")]
+        HashedNameHash::Sha3_256 => hashes::sha3::SHA3_256.file_hash(ctx),
+
+        #[cfg(feature = "sha3")]
+        HashedNameHash::Sha3_384 => hashes::sha3::SHA3_384.file_hash(ctx),
+
+        #[cfg(feature = "sha3")]
+        HashedNameHash::Sha3_512 => hashes::sha3::SHA3_512.file_hash(ctx),
+
+        #[cfg(feature = "blake3")]
+        HashedNameHash::Blake3 => hashes::blake3::BLAKE3.file_hash(ctx),
+
+        // an explicit hash is checked while parsing the attribute, but the default one is not
+        #[allow(unreachable_patterns)]
+        _ => Err(MakeEmbeddedTraitImplementationError::Custom(
+            format!(
+                "`HashedName` with `hash = \"{}\"` requires the `{}` feature",
+                hash.id(),
+                hash.feature()
+            )
+            .into(),
+            None,
+        )),
+    }
+}
+
+impl EmbeddedTrait for HashedNameTrait {
+    fn path(&self, _nesting: usize, _: &GenerationSettings) -> syn::Path {
+        parse_quote!(::embed_it::HashedName)
+    }
+
+    fn impl_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+        _entries: &[EntryTokens],
+        _index: &[IndexTokens],
+    ) -> Option<Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError>> {
+        Some(self.impl_body(ctx))
+    }
+
+    fn definition(&self, _: &GenerationSettings) -> Option<proc_macro2::TokenStream> {
+        None
+    }
+
+    fn id(&self) -> &'static str {
+        "HashedName"
+    }
+
+    fn impl_trait_body(
+        &self,
+        _ctx: &mut GenerateContext<'_>,
+        _entries: &[EntryTokens],
+        _index: &[IndexTokens],
+    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
+        Ok(quote! {
+            fn hashed_path(&self) -> &'static str {
+                self.hashed_path()
+            }
+        })
+    }
+}
@@ -22,6 +22,10 @@ pub const SHA3_384: &AlgId = &AlgId::new("Hash(sha3-384)", "sha3");
 pub const SHA3_512: &AlgId = &AlgId::new("Hash(sha3-512)", "sha3");
 pub const BLAKE3: &AlgId = &AlgId::new("Hash(blake3)", "blake3");
 
+pub const SRI_SHA2_256: &AlgId = &AlgId::new("Integrity(sha2-256)", "sha2");
+pub const SRI_SHA2_384: &AlgId = &AlgId::new("Integrity(sha2-384)", "sha2");
+pub const SRI_SHA2_512: &AlgId = &AlgId::new("Integrity(sha2-512)", "sha2");
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -39,5 +43,8 @@ mod tests {
         assert_eq!(SHA3_384.feature, "sha3");
         assert_eq!(SHA3_512.feature, "sha3");
         assert_eq!(BLAKE3.feature, "blake3");
+        assert_eq!(SRI_SHA2_256.feature, "sha2");
+        assert_eq!(SRI_SHA2_384.feature, "sha2");
+        assert_eq!(SRI_SHA2_512.feature, "sha2");
     }
 }
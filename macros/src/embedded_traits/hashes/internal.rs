@@ -15,14 +15,18 @@ pub mod blake3;
 
 #[cfg(feature = "digest")]
 pub mod digest;
-use std::{collections::HashMap, fmt::Debug, fs::OpenOptions, io::BufReader};
+
+#[cfg(feature = "sha2")]
+pub mod integrity;
+use std::{collections::HashMap, fmt::Debug};
 
 use embed_it_utils::entry::Entry;
 use quote::quote;
 
 use crate::{
-    embed::attributes::embed::GenerationSettings,
+    embed::{EntryTokens, GenerateContext, attributes::embed::GenerationSettings},
     embedded_traits::{EmbeddedTrait, MakeEmbeddedTraitImplementationError},
+    fs::FsInfo,
 };
 
 pub trait HashAlg: Send + Sync {
@@ -41,6 +45,28 @@ pub trait Hasher: std::io::Write {
 #[derive(Debug)]
 pub struct HashTrait<T>(T);
 
+/// Hashes the content of the file
+pub fn hash_file(
+    alg: &impl HashAlg,
+    info: &FsInfo,
+) -> Result<Vec<u8>, MakeEmbeddedTraitImplementationError> {
+    let file_path = info.path().origin_path();
+    let mut reader = info.open().map_err(|e| {
+        MakeEmbeddedTraitImplementationError::with_error(
+            format!("Unable to open file {file_path:?}"),
+            e,
+        )
+    })?;
+    let mut hasher = alg.make_hasher();
+    std::io::copy(&mut reader, &mut hasher).map_err(|e| {
+        MakeEmbeddedTraitImplementationError::with_error(
+            format!("Unable to hash content of {file_path:?}"),
+            e,
+        )
+    })?;
+    Ok(hasher.finalize())
+}
+
 impl<T: HashAlg + Debug> HashTrait<T> {
     #[cfg(any(
         feature = "md5",
@@ -58,13 +84,34 @@ impl<T: HashAlg + Debug> HashTrait<T> {
 struct Hashes(HashMap<&'static str, Vec<u8>>);
 
 impl<T: HashAlg + Debug> HashTrait<T> {
-    fn impl_body(
+    /// Returns the hash of the current file. If the trait is derived for the file
+    /// before, the computed hash is reused
+    pub fn file_hash(
         &self,
-        ctx: &mut crate::embed::GenerateContext<'_>,
-        entries: &[crate::embed::EntryTokens],
-    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
-        let hash = match &ctx.entry {
+        ctx: &GenerateContext<'_>,
+    ) -> Result<Vec<u8>, MakeEmbeddedTraitImplementationError> {
+        let computed = ctx.items.get::<Hashes>().and_then(|h| h.0.get(self.id()));
+        match computed {
+            Some(hash) => Ok(hash.clone()),
+            None => hash_file(&self.0, ctx.entry_info()),
+        }
+    }
+}
+
+impl<T: HashAlg + Debug> HashTrait<T> {
+    /// Returns the hash of the current entry. If the trait is derived for the entry
+    /// before, the computed hash is reused
+    fn entry_hash(
+        &self,
+        ctx: &GenerateContext<'_>,
+        entries: &[EntryTokens],
+    ) -> Result<Vec<u8>, MakeEmbeddedTraitImplementationError> {
+        match &ctx.entry {
             Entry::Dir(_) => {
+                if let Some(hash) = ctx.items.get::<Hashes>().and_then(|h| h.0.get(self.id())) {
+                    return Ok(hash.clone());
+                }
+
                 let mut hasher = self.0.make_hasher();
                 for entry in entries {
                     let name = &entry.entry.as_ref().value().path().file_name;
@@ -76,35 +123,18 @@ impl<T: HashAlg + Debug> HashTrait<T> {
                     }
                 }
 
-                hasher.finalize()
-            }
-            Entry::File(info) => {
-                let file_path = info.path().origin_path();
-                let file = OpenOptions::new()
-                    .read(true)
-                    .create(false)
-                    .create_new(false)
-                    .append(false)
-                    .write(false)
-                    .truncate(false)
-                    .open(file_path)
-                    .map_err(|e| {
-                        MakeEmbeddedTraitImplementationError::with_error(
-                            format!("Unable to open file {file_path:?}"),
-                            e,
-                        )
-                    })?;
-                let mut reader = BufReader::new(file);
-                let mut hasher = self.0.make_hasher();
-                std::io::copy(&mut reader, &mut hasher).map_err(|e| {
-                    MakeEmbeddedTraitImplementationError::with_error(
-                        format!("Unable to hash content of {file_path:?}"),
-                        e,
-                    )
-                })?;
-                hasher.finalize()
+                Ok(hasher.finalize())
             }
-        };
+            Entry::File(_) => self.file_hash(ctx),
+        }
+    }
+
+    fn impl_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+        entries: &[EntryTokens],
+    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
+        let hash = self.entry_hash(ctx, entries)?;
 
         let hash_len = self.0.output_size();
         debug_assert!(
@@ -150,6 +180,17 @@ impl<T: HashAlg + Debug> EmbeddedTrait for HashTrait<T> {
     ) -> Option<Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError>> {
         Some(self.impl_body(ctx, entries))
     }
+
+    fn entry_hash(
+        &self,
+        ctx: &GenerateContext<'_>,
+        entries: &[crate::embed::EntryTokens],
+    ) -> Option<Result<(syn::Ident, Vec<u8>), MakeEmbeddedTraitImplementationError>> {
+        Some(
+            self.entry_hash(ctx, entries)
+                .map(|hash| (self.0.trait_method(), hash)),
+        )
+    }
     fn impl_trait_body(
         &self,
         _ctx: &mut crate::embed::GenerateContext<'_>,
@@ -0,0 +1,85 @@
+use std::fmt::Debug;
+
+use embed_it_utils::entry::EntryKind;
+use quote::quote;
+use syn::parse_quote;
+
+use crate::{
+    embed::{EntryTokens, GenerateContext, IndexTokens, attributes::embed::GenerationSettings},
+    embedded_traits::{EmbeddedTrait, MakeEmbeddedTraitImplementationError},
+    utils::base64,
+};
+
+use super::{HashAlg, HashTrait};
+
+/// Subresource Integrity string (`<prefix>-<base64 of the hash>`) of a file
+#[derive(Debug)]
+pub struct IntegrityTrait<T: 'static> {
+    id: &'static str,
+    prefix: &'static str,
+    hash: &'static HashTrait<T>,
+}
+
+impl<T: HashAlg + Debug> IntegrityTrait<T> {
+    pub const fn new(id: &'static str, prefix: &'static str, hash: &'static HashTrait<T>) -> Self {
+        Self { id, prefix, hash }
+    }
+
+    fn impl_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
+        if ctx.entry.kind() != EntryKind::File {
+            return Err(MakeEmbeddedTraitImplementationError::UnsupportedEntry {
+                entry: ctx.entry.kind(),
+                trait_id: self.id(),
+            });
+        }
+
+        let hash = self.hash.file_hash(ctx)?;
+
+        let value = format!("{}-{}", self.prefix, base64::encode(&hash));
+        Ok(quote! {
+            pub fn integrity(&self) -> &'static str {
+                const VALUE: &str = #value;
+                VALUE
+            }
+        })
+    }
+}
+
+impl<T: HashAlg + Debug> EmbeddedTrait for IntegrityTrait<T> {
+    fn id(&self) -> &'static str {
+        self.id
+    }
+
+    fn path(&self, _: usize, _: &GenerationSettings) -> syn::Path {
+        parse_quote!(::embed_it::Integrity)
+    }
+
+    fn definition(&self, _: &GenerationSettings) -> Option<proc_macro2::TokenStream> {
+        None
+    }
+
+    fn impl_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+        _entries: &[EntryTokens],
+        _index: &[IndexTokens],
+    ) -> Option<Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError>> {
+        Some(self.impl_body(ctx))
+    }
+
+    fn impl_trait_body(
+        &self,
+        _ctx: &mut GenerateContext<'_>,
+        _entries: &[EntryTokens],
+        _index: &[IndexTokens],
+    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
+        Ok(quote! {
+            fn integrity(&self) -> &'static str {
+                self.integrity()
+            }
+        })
+    }
+}
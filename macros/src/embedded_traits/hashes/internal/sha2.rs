@@ -3,7 +3,7 @@ use syn::parse_quote;
 
 use crate::embedded_traits::hashes::ids;
 
-use super::{HashTrait, digest::DigestHashAlg};
+use super::{HashTrait, digest::DigestHashAlg, integrity::IntegrityTrait};
 
 pub const SHA2_224: &HashTrait<DigestHashAlg<Sha224>> = &HashTrait::new(DigestHashAlg::new(
     ids::SHA2_224.id,
@@ -29,6 +29,15 @@ pub const SHA2_512: &HashTrait<DigestHashAlg<Sha512>> = &HashTrait::new(DigestHa
     || parse_quote!(sha2_512),
 ));
 
+pub const SRI_SHA2_256: &IntegrityTrait<DigestHashAlg<Sha256>> =
+    &IntegrityTrait::new(ids::SRI_SHA2_256.id, "sha256", SHA2_256);
+
+pub const SRI_SHA2_384: &IntegrityTrait<DigestHashAlg<Sha384>> =
+    &IntegrityTrait::new(ids::SRI_SHA2_384.id, "sha384", SHA2_384);
+
+pub const SRI_SHA2_512: &IntegrityTrait<DigestHashAlg<Sha512>> =
+    &IntegrityTrait::new(ids::SRI_SHA2_512.id, "sha512", SHA2_512);
+
 #[cfg(test)]
 mod tests {
     use hex_literal::hex;
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use embed_it_utils::entry::EntryKind;
 use quote::quote;
 use syn::parse_quote;
@@ -5,7 +7,7 @@ use syn::parse_quote;
 use crate::{
     embed::{
         EntryTokens, GenerateContext, IndexTokens, attributes::embed::GenerationSettings,
-        bool_like_enum::BoolLikeEnum,
+        attributes::index::IndexKind, bool_like_enum::BoolLikeEnum,
     },
     embedded_traits::MakeEmbeddedTraitImplementationError,
     utils::entry_ext::EntryKindExt,
@@ -28,6 +30,86 @@ impl IndexTrait {
                 trait_id: self.id(),
             });
         }
+        Self::check_collisions(ctx, index)?;
+        match ctx.settings.index.kind {
+            IndexKind::HashMap => Ok(Self::hash_map(ctx, index)),
+            IndexKind::Sorted => Ok(Self::sorted(ctx, index)),
+        }
+    }
+
+    /// Different entries must not have the same key, e.g. `a.txt` and `A.txt` with `case_insensitive`
+    /// or a file named like the hashed name of another file, see [`crate::embed::IndexAliases`]
+    fn check_collisions(
+        ctx: &GenerateContext<'_>,
+        index: &[IndexTokens],
+    ) -> Result<(), MakeEmbeddedTraitImplementationError> {
+        let mut keys = HashMap::with_capacity(index.len() + 1);
+        keys.insert(String::new(), ("", false));
+        for tokens in index {
+            let key = ctx.settings.index.key(&tokens.relative_path);
+            let Some((prev, prev_alias)) =
+                keys.insert(key, (tokens.relative_path.as_str(), tokens.alias))
+            else {
+                continue;
+            };
+            let origin = &ctx.entry.as_ref().value().path().origin;
+            let message = if prev_alias || tokens.alias {
+                let alias = if prev_alias {
+                    prev
+                } else {
+                    &tokens.relative_path
+                };
+                format!(
+                    "the hashed name `{alias}` collides with another entry in the index of `{origin}`"
+                )
+            } else {
+                format!(
+                    "`{prev}` and `{}` collide in the index of `{origin}`",
+                    tokens.relative_path
+                )
+            };
+            return Err(MakeEmbeddedTraitImplementationError::Custom(
+                message.into(),
+                None,
+            ));
+        }
+        Ok(())
+    }
+
+    fn lookup_options(ctx: &GenerateContext<'_>) -> proc_macro2::TokenStream {
+        let settings = &ctx.settings.index;
+        let alt_separator = ctx.settings.support_alt_separator.as_bool();
+        let case_insensitive = settings.case_insensitive.as_bool();
+        let normalize = settings.normalize_path.as_bool();
+        let percent_decode = settings.percent_decode.as_bool();
+        quote! {
+            ::embed_it::LookupOptions {
+                alt_separator: #alt_separator,
+                case_insensitive: #case_insensitive,
+                normalize: #normalize,
+                percent_decode: #percent_decode,
+            }
+        }
+    }
+
+    /// Looks up a path inside a mounted embed by its own `get`, if the path is not found in the index
+    fn mounted(ctx: &GenerateContext<'_>, index: &[IndexTokens]) -> proc_macro2::TokenStream {
+        let options = Self::lookup_options(ctx);
+        index
+            .iter()
+            .filter(|i| i.mount)
+            .fold(quote! {}, |mut acc, tokens| {
+                let key = ctx.settings.index.key(&tokens.relative_path);
+                let struct_path = &tokens.struct_path;
+                acc.extend(quote! {
+                    .or_else(|| #options.strip_mount(path, #key).and_then(|rest| #struct_path.get(rest)))
+                });
+                acc
+            })
+    }
+
+    fn hash_map(ctx: &GenerateContext<'_>, index: &[IndexTokens]) -> proc_macro2::TokenStream {
+        let mounted = Self::mounted(ctx, index);
         let entry_path = &ctx.settings.entry_path(ctx.level);
         let index_len = index.len();
 
@@ -46,8 +128,9 @@ impl IndexTrait {
                 } = tokens;
                 let kind_ident = kind.ident();
                 let entry_struct_path = ctx.settings.entry_param_for(*kind, ctx.level);
+                let key = ctx.settings.index.key(relative_path);
                 acc.extend(quote! {
-                    map.insert(#relative_path, ::embed_it::Entry::#kind_ident(#entry_struct_path(&#struct_path)));
+                    map.insert(#key, ::embed_it::Entry::#kind_ident(#entry_struct_path(&#struct_path)));
                 });
                 acc
             });
@@ -56,7 +139,12 @@ impl IndexTrait {
             #index
             map
         };
-        let value_get = if ctx.settings.support_alt_separator.as_bool() {
+        let value_get = if !ctx.settings.index.is_exact() {
+            let options = Self::lookup_options(ctx);
+            quote! {
+                #options.normalize(path).and_then(|path| VALUE.get(path.as_str()))
+            }
+        } else if ctx.settings.support_alt_separator.as_bool() {
             quote! {
                 VALUE.get(path.replace("\\", "/").as_str())
             }
@@ -66,14 +154,65 @@ impl IndexTrait {
             }
         };
 
-        Ok(quote! {
+        quote! {
             pub fn get(&self, path: &str) -> Option<&'static #entry_path> {
                 static VALUE: ::std::sync::LazyLock<::std::collections::HashMap<&'static str, #entry_path>> = ::std::sync::LazyLock::new(|| {
                     #index
                 });
-                #value_get
+                #value_get #mounted
             }
-        })
+        }
+    }
+
+    fn sorted(ctx: &GenerateContext<'_>, index: &[IndexTokens]) -> proc_macro2::TokenStream {
+        let entry_path = &ctx.settings.entry_path(ctx.level);
+        let struct_ident = &ctx.entry_struct_ident();
+        let entry_struct_path = ctx.settings.dir_entry_param(ctx.level);
+
+        let mut sorted = index
+            .iter()
+            .map(|tokens| (ctx.settings.index.key(&tokens.relative_path), tokens))
+            .collect::<Vec<_>>();
+        sorted.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let items = sorted.into_iter().map(|(key, tokens)| {
+            let IndexTokens {
+                struct_path, kind, ..
+            } = tokens;
+            let kind_ident = kind.ident();
+            let entry_struct_path = ctx.settings.entry_param_for(*kind, ctx.level);
+            quote! {
+                (#key, ::embed_it::Entry::#kind_ident(#entry_struct_path(&#struct_path))),
+            }
+        });
+
+        let value_get = if !ctx.settings.index.is_exact() {
+            let options = Self::lookup_options(ctx);
+            quote! {
+                VALUE.get_with(path, #options)
+            }
+        } else if ctx.settings.support_alt_separator.as_bool() {
+            quote! {
+                VALUE.get_with_alt_separator(path)
+            }
+        } else {
+            quote! {
+                VALUE.get(path)
+            }
+        };
+
+        // a mounted embed may have a non-const `get`
+        let constness = (!index.iter().any(|i| i.mount)).then(|| quote! { const });
+        let mounted = Self::mounted(ctx, index);
+        quote! {
+            pub #constness fn get(&self, path: &str) -> Option<&'static #entry_path> {
+                const VALUE: ::embed_it::SortedIndex<#entry_path> = ::embed_it::SortedIndex::new(&[
+                    ("", ::embed_it::Entry::Dir(#entry_struct_path(&#struct_ident))),
+                    #(#items)*
+                ]);
+                #value_get #mounted
+            }
+        }
     }
 }
 
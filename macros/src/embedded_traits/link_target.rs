@@ -0,0 +1,72 @@
+use embed_it_utils::entry::EntryKind;
+use quote::quote;
+use syn::parse_quote;
+
+use crate::embed::{
+    EntryTokens, GenerateContext, IndexTokens, attributes::embed::GenerationSettings,
+};
+
+use super::{EmbeddedTrait, MakeEmbeddedTraitImplementationError};
+
+#[derive(Debug)]
+pub struct LinkTargetTrait;
+
+impl LinkTargetTrait {
+    fn impl_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
+        if ctx.entry.kind() != EntryKind::File {
+            return Err(MakeEmbeddedTraitImplementationError::UnsupportedEntry {
+                entry: ctx.entry.kind(),
+                trait_id: self.id(),
+            });
+        }
+
+        let value = match ctx.entry_info().link_target() {
+            Some(target) => quote! { Some(#target) },
+            None => quote! { None },
+        };
+        Ok(quote! {
+            pub const fn link_target(&self) -> Option<&'static str> {
+                #value
+            }
+        })
+    }
+}
+
+impl EmbeddedTrait for LinkTargetTrait {
+    fn path(&self, _nesting: usize, _: &GenerationSettings) -> syn::Path {
+        parse_quote!(::embed_it::LinkTarget)
+    }
+
+    fn impl_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+        _entries: &[EntryTokens],
+        _index: &[IndexTokens],
+    ) -> Option<Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError>> {
+        Some(self.impl_body(ctx))
+    }
+
+    fn definition(&self, _: &GenerationSettings) -> Option<proc_macro2::TokenStream> {
+        None
+    }
+
+    fn id(&self) -> &'static str {
+        "LinkTarget"
+    }
+
+    fn impl_trait_body(
+        &self,
+        _ctx: &mut GenerateContext<'_>,
+        _entries: &[EntryTokens],
+        _index: &[IndexTokens],
+    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
+        Ok(quote! {
+            fn link_target(&self) -> Option<&'static str> {
+                self.link_target()
+            }
+        })
+    }
+}
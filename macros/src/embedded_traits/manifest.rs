@@ -0,0 +1,149 @@
+use embed_it_utils::entry::{Entry, EntryKind};
+use quote::quote;
+use syn::parse_quote;
+
+use crate::{
+    embed::{EntryTokens, GenerateContext, IndexTokens, attributes::embed::GenerationSettings},
+    utils::entry_ext::EntryKindExt,
+};
+
+use super::{
+    EmbeddedTrait, MakeEmbeddedTraitImplementationError, TraitAttr, meta::metadata_constructor,
+    path::embedded_path_constructor,
+};
+
+#[derive(Debug)]
+pub struct ManifestTrait;
+
+fn method() -> syn::Ident {
+    parse_quote!(manifest)
+}
+
+impl ManifestTrait {
+    fn hashes(
+        &self,
+        ctx: &GenerateContext<'_>,
+        entries: &[EntryTokens],
+    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
+        let traits = ctx
+            .settings
+            .trait_for(ctx.entry.kind())
+            .map(
+                |d| d.embedded_traits().collect::<Vec<_>>(),
+                |f| f.embedded_traits().collect::<Vec<_>>(),
+            )
+            .value();
+
+        let mut hashes = quote! {};
+        for t in traits {
+            let Some(hash) = t.entry_hash(ctx, entries) else {
+                continue;
+            };
+            let (name, hash) = hash?;
+            let name = name.to_string();
+            let hex = hash.iter().map(|b| format!("{b:02x}")).collect::<String>();
+            hashes.extend(quote! {
+                ::embed_it::ManifestHash { name: #name, hex: #hex },
+            });
+        }
+
+        Ok(hashes)
+    }
+
+    fn impl_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+        entries: &[EntryTokens],
+    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
+        // a dir refers to the manifests of its children, so files must have it too
+        if ctx.entry.kind() == EntryKind::Dir
+            && !ctx.settings.file.is_trait_implemented(&ManifestTrait)
+        {
+            return Err(MakeEmbeddedTraitImplementationError::Custom(
+                "`Manifest` for dirs requires `Manifest` to be derived for files".into(),
+                None,
+            ));
+        }
+
+        let path = embedded_path_constructor(ctx);
+        let metadata = metadata_constructor(ctx);
+        let hashes = self.hashes(ctx, entries)?;
+        let method = method();
+
+        let value = match &ctx.entry {
+            Entry::Dir(_) => {
+                let children = entries.iter().fold(quote! {}, |mut children, tokens| {
+                    let struct_path = &tokens.struct_path;
+                    children.extend(quote! {
+                        #struct_path.#method(),
+                    });
+                    children
+                });
+                quote! {
+                    const VALUE: &::embed_it::ManifestDir = &::embed_it::ManifestDir {
+                        path: #path,
+                        metadata: #metadata,
+                        hashes: &[#hashes],
+                        entries: &[#children],
+                    };
+                }
+            }
+            Entry::File(info) => {
+                let size = info.metadata().len();
+                quote! {
+                    const VALUE: &::embed_it::ManifestFile = &::embed_it::ManifestFile {
+                        path: #path,
+                        metadata: #metadata,
+                        size: #size,
+                        hashes: &[#hashes],
+                    };
+                }
+            }
+        };
+
+        let kind_ident = ctx.entry.kind().ident();
+        Ok(quote! {
+            pub const fn #method(&self) -> ::embed_it::ManifestEntry {
+                #value
+                ::embed_it::Entry::#kind_ident(VALUE)
+            }
+        })
+    }
+}
+
+impl EmbeddedTrait for ManifestTrait {
+    fn path(&self, _nesting: usize, _: &GenerationSettings) -> syn::Path {
+        parse_quote!(::embed_it::Manifest)
+    }
+
+    fn impl_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+        entries: &[EntryTokens],
+        _index: &[IndexTokens],
+    ) -> Option<Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError>> {
+        Some(self.impl_body(ctx, entries))
+    }
+
+    fn definition(&self, _: &GenerationSettings) -> Option<proc_macro2::TokenStream> {
+        None
+    }
+
+    fn id(&self) -> &'static str {
+        "Manifest"
+    }
+
+    fn impl_trait_body(
+        &self,
+        _ctx: &mut GenerateContext<'_>,
+        _entries: &[EntryTokens],
+        _index: &[IndexTokens],
+    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
+        let method = method();
+        Ok(quote! {
+            fn #method(&self) -> ::embed_it::ManifestEntry {
+                self.#method()
+            }
+        })
+    }
+}
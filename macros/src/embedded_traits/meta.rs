@@ -16,42 +16,50 @@ fn method() -> syn::Ident {
     parse_quote!(metadata)
 }
 
-impl MetaTrait {
-    fn impl_body(
-        &self,
-        ctx: &mut GenerateContext<'_>,
-    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
-        fn unixtime(t: SystemTime) -> Duration {
-            t.duration_since(SystemTime::UNIX_EPOCH).unwrap()
-        }
+/// The `::embed_it::Metadata` constructor of the current entry
+pub fn metadata_constructor(ctx: &GenerateContext<'_>) -> proc_macro2::TokenStream {
+    fn unixtime(t: SystemTime) -> Duration {
+        t.duration_since(SystemTime::UNIX_EPOCH).unwrap()
+    }
 
-        fn constructor(t: SystemTime) -> proc_macro2::TokenStream {
-            let duration = unixtime(t);
-            let secs = duration.as_secs();
-            let nanos = duration.subsec_nanos();
-            quote! {
-                Some(std::time::Duration::new(#secs, #nanos))
-            }
+    fn constructor(t: SystemTime) -> proc_macro2::TokenStream {
+        let duration = unixtime(t);
+        let secs = duration.as_secs();
+        let nanos = duration.subsec_nanos();
+        quote! {
+            Some(::core::time::Duration::new(#secs, #nanos))
         }
+    }
 
-        fn make_stream(value: std::io::Result<SystemTime>) -> proc_macro2::TokenStream {
-            value.ok().map(constructor).unwrap_or_else(|| quote! {None})
-        }
+    fn make_stream(value: Option<SystemTime>) -> proc_macro2::TokenStream {
+        value.map(constructor).unwrap_or_else(|| quote! {None})
+    }
 
-        let meta = &ctx.entry.as_ref().value().metadata();
+    let meta = &ctx.entry.as_ref().value().metadata();
 
-        let accessed = make_stream(meta.accessed());
-        let created = make_stream(meta.created());
-        let modified = make_stream(meta.modified());
+    let accessed = make_stream(meta.accessed());
+    let created = make_stream(meta.created());
+    let modified = make_stream(meta.modified());
 
+    quote! {
+        ::embed_it::Metadata::new(
+            #accessed,
+            #created,
+            #modified,
+        )
+    }
+}
+
+impl MetaTrait {
+    fn impl_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
+        let constructor = metadata_constructor(ctx);
         let method = method();
         Ok(quote! {
             pub fn #method(&self) -> &'static ::embed_it::Metadata {
-                const VALUE: &::embed_it::Metadata = &::embed_it::Metadata::new(
-                    #accessed,
-                    #created,
-                    #modified,
-                );
+                const VALUE: &::embed_it::Metadata = &#constructor;
                 VALUE
             }
         })
@@ -0,0 +1,115 @@
+mod extensions;
+mod sniff;
+
+use std::io::Read;
+
+use embed_it_utils::entry::EntryKind;
+use quote::quote;
+use syn::parse_quote;
+
+use crate::{
+    embed::{
+        EntryTokens, GenerateContext, IndexTokens, attributes::embed::GenerationSettings,
+        bool_like_enum::BoolLikeEnum,
+    },
+    embedded_traits::EmbeddedTrait,
+    fs::FsInfo,
+};
+
+use super::MakeEmbeddedTraitImplementationError;
+
+const DEFAULT_MIME: &str = "application/octet-stream";
+
+#[derive(Debug)]
+pub struct MimeTrait;
+
+impl MimeTrait {
+    fn impl_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
+        if ctx.entry.kind() != EntryKind::File {
+            return Err(MakeEmbeddedTraitImplementationError::UnsupportedEntry {
+                entry: ctx.entry.kind(),
+                trait_id: self.id(),
+            });
+        }
+
+        let mime = resolve(ctx.entry_info(), ctx.settings)?;
+        Ok(quote! {
+            pub fn mime(&self) -> &'static str {
+                const VALUE: &str = #mime;
+                VALUE
+            }
+        })
+    }
+}
+
+/// An explicit mapping wins, then the extension and then the content, if sniffing is enabled
+fn resolve(
+    info: &FsInfo,
+    settings: &GenerationSettings,
+) -> Result<String, MakeEmbeddedTraitImplementationError> {
+    let path = info.path();
+    let mime_settings = settings.file.mime();
+    if let Some(mime) = mime_settings.mapped(path) {
+        return Ok(mime.to_owned());
+    }
+
+    if let Some(mime) = extensions::by_extension(&path.file_name) {
+        return Ok(mime.to_owned());
+    }
+
+    if !mime_settings.sniff.as_bool() {
+        return Ok(DEFAULT_MIME.to_owned());
+    }
+
+    let file_path = path.origin_path();
+    let mut head = Vec::with_capacity(sniff::SNIFF_LEN);
+    info.open()
+        .and_then(|f| f.take(sniff::SNIFF_LEN as u64).read_to_end(&mut head))
+        .map_err(|e| {
+            MakeEmbeddedTraitImplementationError::with_error(
+                format!("Unable to read file {file_path:?}"),
+                e,
+            )
+        })?;
+
+    Ok(sniff::by_content(&head).unwrap_or(DEFAULT_MIME).to_owned())
+}
+
+impl EmbeddedTrait for MimeTrait {
+    fn path(&self, _nesting: usize, _: &GenerationSettings) -> syn::Path {
+        parse_quote!(::embed_it::Mime)
+    }
+
+    fn impl_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+        _entries: &[EntryTokens],
+        _index: &[IndexTokens],
+    ) -> Option<Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError>> {
+        Some(self.impl_body(ctx))
+    }
+
+    fn definition(&self, _: &GenerationSettings) -> Option<proc_macro2::TokenStream> {
+        None
+    }
+
+    fn id(&self) -> &'static str {
+        "Mime"
+    }
+
+    fn impl_trait_body(
+        &self,
+        _ctx: &mut GenerateContext<'_>,
+        _entries: &[EntryTokens],
+        _index: &[IndexTokens],
+    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
+        Ok(quote! {
+            fn mime(&self) -> &'static str {
+                self.mime()
+            }
+        })
+    }
+}
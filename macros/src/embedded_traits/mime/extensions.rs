@@ -0,0 +1,109 @@
+/// Well known mime types by a lowercase file extension.
+/// Text types have the utf8 charset, so a browser doesn't guess the encoding
+const EXTENSIONS: &[(&str, &str)] = &[
+    // text
+    ("css", "text/css; charset=utf-8"),
+    ("csv", "text/csv; charset=utf-8"),
+    ("htm", "text/html; charset=utf-8"),
+    ("html", "text/html; charset=utf-8"),
+    ("ics", "text/calendar; charset=utf-8"),
+    ("js", "text/javascript; charset=utf-8"),
+    ("md", "text/markdown; charset=utf-8"),
+    ("mjs", "text/javascript; charset=utf-8"),
+    ("txt", "text/plain; charset=utf-8"),
+    ("vtt", "text/vtt; charset=utf-8"),
+    // application
+    ("7z", "application/x-7z-compressed"),
+    ("atom", "application/atom+xml"),
+    ("bin", "application/octet-stream"),
+    ("bz2", "application/x-bzip2"),
+    ("epub", "application/epub+zip"),
+    ("gz", "application/gzip"),
+    ("jar", "application/java-archive"),
+    ("json", "application/json; charset=utf-8"),
+    ("jsonld", "application/ld+json; charset=utf-8"),
+    ("map", "application/json; charset=utf-8"),
+    ("pdf", "application/pdf"),
+    ("rss", "application/rss+xml"),
+    ("rtf", "application/rtf"),
+    ("tar", "application/x-tar"),
+    ("toml", "application/toml"),
+    ("wasm", "application/wasm"),
+    ("webmanifest", "application/manifest+json; charset=utf-8"),
+    ("xhtml", "application/xhtml+xml"),
+    ("xml", "application/xml"),
+    ("xz", "application/x-xz"),
+    ("yaml", "application/yaml"),
+    ("yml", "application/yaml"),
+    ("zip", "application/zip"),
+    ("zst", "application/zstd"),
+    // images
+    ("apng", "image/apng"),
+    ("avif", "image/avif"),
+    ("bmp", "image/bmp"),
+    ("gif", "image/gif"),
+    ("ico", "image/x-icon"),
+    ("jpeg", "image/jpeg"),
+    ("jpg", "image/jpeg"),
+    ("png", "image/png"),
+    ("svg", "image/svg+xml"),
+    ("tif", "image/tiff"),
+    ("tiff", "image/tiff"),
+    ("webp", "image/webp"),
+    // fonts
+    ("eot", "application/vnd.ms-fontobject"),
+    ("otf", "font/otf"),
+    ("ttf", "font/ttf"),
+    ("woff", "font/woff"),
+    ("woff2", "font/woff2"),
+    // audio
+    ("aac", "audio/aac"),
+    ("flac", "audio/flac"),
+    ("m4a", "audio/mp4"),
+    ("mid", "audio/midi"),
+    ("midi", "audio/midi"),
+    ("mp3", "audio/mpeg"),
+    ("oga", "audio/ogg"),
+    ("opus", "audio/opus"),
+    ("wav", "audio/wav"),
+    // video
+    ("avi", "video/x-msvideo"),
+    ("mov", "video/quicktime"),
+    ("mp4", "video/mp4"),
+    ("mpeg", "video/mpeg"),
+    ("ogv", "video/ogg"),
+    ("webm", "video/webm"),
+];
+
+/// Finds a mime type by the extension of the file name
+pub fn by_extension(file_name: &str) -> Option<&'static str> {
+    let (_, extension) = file_name.rsplit_once('.')?;
+    let extension = extension.to_ascii_lowercase();
+    EXTENSIONS
+        .iter()
+        .find(|(e, _)| *e == extension)
+        .map(|(_, mime)| *mime)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::by_extension;
+
+    #[test]
+    fn known() {
+        assert_eq!(by_extension("index.html"), Some("text/html; charset=utf-8"));
+        assert_eq!(by_extension("logo.PNG"), Some("image/png"));
+        assert_eq!(by_extension("archive.tar.gz"), Some("application/gzip"));
+        assert_eq!(
+            by_extension(".json"),
+            Some("application/json; charset=utf-8")
+        );
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(by_extension("hello"), None);
+        assert_eq!(by_extension("hello."), None);
+        assert_eq!(by_extension("hello.unknown"), None);
+    }
+}
@@ -0,0 +1,209 @@
+/// How many bytes from the beginning of a file are inspected
+pub const SNIFF_LEN: usize = 512;
+
+/// Binary signatures: an offset, the expected bytes and a mime type
+const SIGNATURES: &[(usize, &[u8], &str)] = &[
+    (0, b"\x89PNG\r\n\x1a\n", "image/png"),
+    (0, b"\xff\xd8\xff", "image/jpeg"),
+    (0, b"GIF87a", "image/gif"),
+    (0, b"GIF89a", "image/gif"),
+    (4, b"ftypavif", "image/avif"),
+    (4, b"ftyp", "video/mp4"),
+    (0, b"%PDF-", "application/pdf"),
+    (0, b"PK\x03\x04", "application/zip"),
+    (0, b"\x1f\x8b", "application/gzip"),
+    (0, b"\x28\xb5\x2f\xfd", "application/zstd"),
+    (0, b"7z\xbc\xaf\x27\x1c", "application/x-7z-compressed"),
+    (0, b"\x00asm", "application/wasm"),
+    (0, b"wOFF", "font/woff"),
+    (0, b"wOF2", "font/woff2"),
+    (0, b"OTTO", "font/otf"),
+    (0, b"\x00\x01\x00\x00", "font/ttf"),
+    (0, b"ID3", "audio/mpeg"),
+    (0, b"OggS", "audio/ogg"),
+    (0, b"fLaC", "audio/flac"),
+    (0, b"\x1a\x45\xdf\xa3", "video/webm"),
+];
+
+/// Markup prefixes, which are compared case insensitively after leading whitespaces
+const MARKUPS: &[(&[u8], &str)] = &[
+    (b"<!doctype html", "text/html; charset=utf-8"),
+    (b"<html", "text/html; charset=utf-8"),
+    (b"<svg", "image/svg+xml"),
+    (b"<?xml", "application/xml"),
+];
+
+/// Guesses a mime type by the beginning of the content. Nothing is guessed for an empty file
+pub fn by_content(head: &[u8]) -> Option<&'static str> {
+    if head.is_empty() {
+        return None;
+    }
+
+    if let Some(mime) = by_header(head) {
+        return Some(mime);
+    }
+
+    if let Some(mime) = SIGNATURES
+        .iter()
+        .find(|(offset, signature, _)| {
+            head.get(*offset..*offset + signature.len()) == Some(signature)
+        })
+        .map(|(_, _, mime)| *mime)
+    {
+        return Some(mime);
+    }
+
+    let text = head.strip_prefix(b"\xef\xbb\xbf").unwrap_or(head);
+    let text = text.trim_ascii_start();
+    if let Some(mime) = MARKUPS
+        .iter()
+        .find(|(prefix, _)| {
+            text.get(..prefix.len())
+                .is_some_and(|v| v.eq_ignore_ascii_case(prefix))
+        })
+        .map(|(_, mime)| *mime)
+    {
+        return Some(mime);
+    }
+
+    is_text(head).then_some("text/plain; charset=utf-8")
+}
+
+/// Formats with short or nested signatures, which are recognized only with a valid header,
+/// so a text file, which starts with `BM`, is not an image
+fn by_header(head: &[u8]) -> Option<&'static str> {
+    if head.starts_with(b"RIFF") {
+        return match head.get(8..12) {
+            Some(b"WEBP") => Some("image/webp"),
+            Some(b"WAVE") => Some("audio/wav"),
+            _ => None,
+        };
+    }
+    if is_bmp(head) {
+        return Some("image/bmp");
+    }
+    if is_ico(head) {
+        return Some("image/x-icon");
+    }
+    None
+}
+
+fn u16_at(head: &[u8], offset: usize) -> Option<u16> {
+    let bytes = head.get(offset..offset + 2)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn u32_at(head: &[u8], offset: usize) -> Option<u32> {
+    let bytes = head.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// `BM`, the file size, 4 reserved zero bytes, the offset of pixels and the size of the info header
+fn is_bmp(head: &[u8]) -> bool {
+    const FILE_HEADER_LEN: u32 = 14;
+    if !head.starts_with(b"BM") || head.get(6..10) != Some(&[0; 4]) {
+        return false;
+    }
+    let (Some(file_size), Some(pixels), Some(info_size)) =
+        (u32_at(head, 2), u32_at(head, 10), u32_at(head, 14))
+    else {
+        return false;
+    };
+    [12, 40, 52, 56, 108, 124].contains(&info_size)
+        && pixels >= FILE_HEADER_LEN + info_size
+        && file_size >= pixels
+}
+
+/// 2 reserved zero bytes, the type 1, the number of images and 16 bytes of every image entry,
+/// the first image follows the entries
+fn is_ico(head: &[u8]) -> bool {
+    const HEADER_LEN: u32 = 6;
+    const ENTRY_LEN: u32 = 16;
+    if !head.starts_with(b"\x00\x00\x01\x00") {
+        return false;
+    }
+    let Some(count) = u16_at(head, 4).filter(|count| *count > 0) else {
+        return false;
+    };
+    // the reserved byte of the first entry is zero
+    if head.get(9) != Some(&0) {
+        return false;
+    }
+    u32_at(head, 18).is_some_and(|offset| offset >= HEADER_LEN + ENTRY_LEN * u32::from(count))
+}
+
+fn is_text(head: &[u8]) -> bool {
+    if head.contains(&0) {
+        return false;
+    }
+
+    match std::str::from_utf8(head) {
+        Ok(_) => true,
+        // the head may cut a multibyte char
+        Err(e) => e.error_len().is_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::by_content;
+
+    const TEXT: &str = "text/plain; charset=utf-8";
+    const HTML: &str = "text/html; charset=utf-8";
+
+    #[test]
+    fn signatures() {
+        assert_eq!(by_content(b"\x89PNG\r\n\x1a\n\0\0"), Some("image/png"));
+        assert_eq!(by_content(b"\xff\xd8\xff\xe0"), Some("image/jpeg"));
+        assert_eq!(by_content(b"GIF89a..."), Some("image/gif"));
+        assert_eq!(by_content(b"RIFF\0\0\0\0WEBPVP8 "), Some("image/webp"));
+        assert_eq!(by_content(b"RIFF\0\0\0\0WAVEfmt "), Some("audio/wav"));
+        assert_eq!(by_content(b"\0\0\0\x1cftypavif"), Some("image/avif"));
+        assert_eq!(by_content(b"\0\0\0\x1cftypisom"), Some("video/mp4"));
+        assert_eq!(by_content(b"%PDF-1.7"), Some("application/pdf"));
+        assert_eq!(by_content(b"\x1f\x8b\x08"), Some("application/gzip"));
+        assert_eq!(by_content(b"\0asm\x01\0\0\0"), Some("application/wasm"));
+        assert_eq!(by_content(b"wOF2\0\x01"), Some("font/woff2"));
+    }
+
+    #[test]
+    fn headers() {
+        let bmp = b"BM\x46\0\0\0\0\0\0\0\x36\0\0\0\x28\0\0\0";
+        assert_eq!(by_content(bmp), Some("image/bmp"));
+        let ico = b"\0\0\x01\0\x01\0\x10\x10\0\0\x01\0\x20\0\x68\x04\0\0\x16\0\0\0";
+        assert_eq!(by_content(ico), Some("image/x-icon"));
+
+        assert_eq!(by_content(b"BMW notes"), Some(TEXT));
+        assert_eq!(by_content(b"\0\0\x01\0\0\0"), None);
+        assert_eq!(by_content(b"NOPE\0\0\0\0WEBPVP8 "), None);
+        assert_eq!(by_content(b"notes...WAVE format"), Some(TEXT));
+    }
+
+    #[test]
+    fn markups() {
+        assert_eq!(by_content(b"\n  <!DOCTYPE html><html></html>"), Some(HTML));
+        assert_eq!(by_content(b"<HTML>"), Some(HTML));
+        assert_eq!(
+            by_content(b"\xef\xbb\xbf<svg xmlns=\"http://www.w3.org/2000/svg\"/>"),
+            Some("image/svg+xml")
+        );
+        assert_eq!(
+            by_content(b"<?xml version=\"1.0\"?>"),
+            Some("application/xml")
+        );
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(by_content(b"hello"), Some(TEXT));
+        assert_eq!(by_content("привет".as_bytes()), Some(TEXT));
+        assert_eq!(by_content(&"привет".as_bytes()[..3]), Some(TEXT));
+        assert_eq!(by_content(b""), None);
+    }
+
+    #[test]
+    fn binary() {
+        assert_eq!(by_content(b"hel\0lo"), None);
+        assert_eq!(by_content(b"\xff\xfe\xfd"), None);
+    }
+}
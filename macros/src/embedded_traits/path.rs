@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use quote::quote;
 use syn::parse_quote;
 
@@ -15,22 +17,44 @@ fn method() -> syn::Ident {
     parse_quote!(path)
 }
 
+/// The `::embed_it::EmbeddedPath` constructor of the current entry
+pub fn embedded_path_constructor(ctx: &GenerateContext<'_>) -> proc_macro2::TokenStream {
+    let EntryPath {
+        relative: relative_path,
+        file_name,
+        file_stem,
+        ..
+    } = ctx.entry.as_ref().value().path();
+    let root = ctx.entry_info().root();
+    let relative_path = ctx.settings.mounted_path(relative_path);
+    // the root of a mounted embed is named as its mount point
+    let (file_name, file_stem) = match &ctx.settings.mounted_at {
+        Some(mounted_at) if file_name.is_empty() => {
+            let name = mounted_at.rsplit('/').next().unwrap_or(mounted_at);
+            let stem = Path::new(name)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or(name);
+            (name, stem)
+        }
+        _ => (file_name.as_str(), file_stem.as_str()),
+    };
+
+    quote! {
+        ::embed_it::EmbeddedPath::new(#relative_path, #file_name, #file_stem).with_root(#root)
+    }
+}
+
 impl PathTrait {
     fn impl_body(
         &self,
         ctx: &mut GenerateContext<'_>,
     ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
-        let EntryPath {
-            relative: relative_path,
-            file_name,
-            file_stem,
-            ..
-        } = ctx.entry.as_ref().value().path();
-
+        let constructor = embedded_path_constructor(ctx);
         let method = method();
         Ok(quote! {
             pub fn #method(&self) -> &'static ::embed_it::EmbeddedPath {
-                const VALUE: &::embed_it::EmbeddedPath = &::embed_it::EmbeddedPath::new(#relative_path, #file_name, #file_stem);
+                const VALUE: &::embed_it::EmbeddedPath = &#constructor;
                 VALUE
             }
         })
@@ -29,10 +29,16 @@ impl RecursiveChildCountTrait {
             });
         }
         let method = method();
-        let len = index.len();
+        // hashed names are additional keys of the same entries
+        let len = index.iter().filter(|i| !i.alias).count();
+        // descendants of mounted embeds are counted by them
+        let mounted = index.iter().filter(|i| i.mount).map(|i| {
+            let struct_path = &i.struct_path;
+            quote! { + #struct_path.#method() }
+        });
         Ok(quote! {
             pub fn #method(&self) -> usize {
-                #len
+                #len #(#mounted)*
             }
         })
     }
@@ -4,7 +4,11 @@ use syn::parse_quote;
 
 use crate::{
     embed::{EntryTokens, GenerateContext, IndexTokens, attributes::embed::GenerationSettings},
-    embedded_traits::EmbeddedTrait,
+    embedded_traits::{
+        EmbeddedTrait,
+        compression::stored::{StoredContent, stored_content},
+        content::select_disk_content,
+    },
 };
 
 use super::MakeEmbeddedTraitImplementationError;
@@ -24,12 +28,75 @@ impl StrContentTrait {
             });
         }
 
-        let origin = &ctx.entry.as_ref().value().path().origin;
-        Ok(quote! {
-            pub fn str_content(&self) -> &'static str {
-                const VALUE: &str = include_str!(#origin);
-                VALUE
+        let (definition, embedded) = match stored_content(ctx)? {
+            Some(StoredContent { value, definition }) => {
+                // `include_str!` checks it for the embedded content
+                let file_path = ctx.entry.as_ref().value().path().origin_path();
+                let content = ctx.entry_info().read_content().map_err(|e| {
+                    MakeEmbeddedTraitImplementationError::with_error(
+                        format!("Unable to read file {file_path:?}"),
+                        e,
+                    )
+                })?;
+                std::str::from_utf8(&content).map_err(|e| {
+                    MakeEmbeddedTraitImplementationError::with_error(
+                        format!("File {file_path:?} is not a valid utf8"),
+                        e,
+                    )
+                })?;
+
+                (
+                    definition,
+                    quote! {
+                        pub fn str_content(&self) -> &'static str {
+                            #value.str_content()
+                        }
+                    },
+                )
+            }
+            None => {
+                let value = match ctx.entry_info().inline_content() {
+                    Some(content) => {
+                        // `include_str!` checks it for a file on the disk
+                        let file_path = ctx.entry.as_ref().value().path().origin_path();
+                        let content = std::str::from_utf8(content).map_err(|e| {
+                            MakeEmbeddedTraitImplementationError::with_error(
+                                format!("File {file_path:?} is not a valid utf8"),
+                                e,
+                            )
+                        })?;
+                        quote! { #content }
+                    }
+                    None => {
+                        let origin = &ctx.entry.as_ref().value().path().origin;
+                        quote! { include_str!(#origin) }
+                    }
+                };
+                (
+                    quote! {},
+                    quote! {
+                        pub fn str_content(&self) -> &'static str {
+                            const VALUE: &str = #value;
+                            VALUE
+                        }
+                    },
+                )
             }
+        };
+
+        let str_content = select_disk_content(
+            ctx,
+            quote! {
+                pub fn str_content(&self) -> &'static str {
+                    self.disk_content().str_content()
+                }
+            },
+            embedded,
+        );
+
+        Ok(quote! {
+            #definition
+            #str_content
         })
     }
 }
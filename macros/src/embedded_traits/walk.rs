@@ -0,0 +1,89 @@
+use embed_it_utils::entry::EntryKind;
+use quote::quote;
+use syn::parse_quote;
+
+use crate::{
+    embed::{EntryTokens, GenerateContext, IndexTokens, attributes::embed::GenerationSettings},
+    embedded_traits::MakeEmbeddedTraitImplementationError,
+};
+
+use super::EmbeddedTrait;
+
+#[derive(Debug)]
+pub struct WalkTrait;
+
+impl WalkTrait {
+    fn impl_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
+        if ctx.entry.kind() != EntryKind::Dir {
+            return Err(MakeEmbeddedTraitImplementationError::UnsupportedEntry {
+                entry: ctx.entry.kind(),
+                trait_id: self.id(),
+            });
+        }
+        let entry_path = &ctx.settings.entry_path(ctx.level);
+        let dir = ctx.settings.dir_entry_param(ctx.level);
+        let file = ctx.settings.file_entry_param(ctx.level);
+
+        // the subtree of the dir is a slice of the table of the root, see `embed::walk_table`
+        let root = ctx.make_level_path(ctx.settings.main_struct_ident.clone());
+
+        Ok(quote! {
+            pub fn walk(&self) -> &'static [#entry_path] {
+                &#root::WALK_ENTRIES[Self::WALK_START..Self::WALK_END]
+            }
+
+            pub fn walk_breadth_first(&self) -> ::embed_it::BreadthFirst<#dir, #file> {
+                let depths = &#root::WALK_DEPTHS[Self::WALK_START..Self::WALK_END];
+                ::embed_it::BreadthFirst::new(self.walk(), depths)
+            }
+        })
+    }
+}
+
+impl EmbeddedTrait for WalkTrait {
+    fn path(&self, level: usize, settings: &GenerationSettings) -> syn::Path {
+        let dir = settings.dir_entry_param(level);
+        let file = settings.file_entry_param(level);
+        parse_quote!(::embed_it::Walk<#dir, #file>)
+    }
+
+    fn impl_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+        _entries: &[EntryTokens],
+        _index: &[IndexTokens],
+    ) -> Option<Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError>> {
+        Some(self.impl_body(ctx))
+    }
+
+    fn definition(&self, _: &GenerationSettings) -> Option<proc_macro2::TokenStream> {
+        None
+    }
+
+    fn id(&self) -> &'static str {
+        "Walk"
+    }
+
+    fn impl_trait_body(
+        &self,
+        ctx: &mut GenerateContext<'_>,
+        _: &[EntryTokens],
+        _: &[IndexTokens],
+    ) -> Result<proc_macro2::TokenStream, MakeEmbeddedTraitImplementationError> {
+        let entry_path = &ctx.settings.entry_path(ctx.level);
+        let dir = ctx.settings.dir_entry_param(ctx.level);
+        let file = ctx.settings.file_entry_param(ctx.level);
+        Ok(quote! {
+            fn walk(&self) -> &'static [#entry_path] {
+                self.walk()
+            }
+
+            fn walk_breadth_first(&self) -> ::embed_it::BreadthFirst<#dir, #file> {
+                self.walk_breadth_first()
+            }
+        })
+    }
+}
@@ -1,31 +1,174 @@
 use std::{
     borrow::Cow,
+    collections::HashMap,
     env::VarError,
+    ffi::OsString,
     fmt::Debug,
-    fs::read_dir,
-    io,
-    path::{Path, PathBuf},
+    fs::{File, read_dir},
+    io::{self, BufReader, Read},
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+    time::SystemTime,
 };
 
 use convert_case::{Case, Casing};
 use embed_it_utils::entry::{Entry, EntryKind};
 use proc_macro2::Span;
 use quote::ToTokens;
-use regex::{Captures, Regex};
 use syn::Ident;
 use unicode_ident::{is_xid_continue, is_xid_start};
 
 use crate::{
-    embed::{attributes::with_extension::WithExtension, bool_like_enum::BoolLikeEnum},
+    embed::{
+        attributes::{conflicts::Conflicts, symlinks::Symlinks, with_extension::WithExtension},
+        bool_like_enum::BoolLikeEnum,
+    },
     utils::unique_names::UniqueIdents,
 };
 
+mod archive;
+mod ignore_files;
+
+pub use archive::{Archive, ArchiveEntry, ReadArchiveError};
+pub use ignore_files::{IgnoreFiles, ReadIgnoreFilesError};
+
 const REPLACEMENT_IDENT_CHAR: char = '_';
 
 #[derive(Debug)]
 pub struct FsInfo {
     path: EntryPath,
-    metadata: std::fs::Metadata,
+    metadata: EntryMetadata,
+
+    /// The target of a symlink, which is embedded as a link (see [`Symlinks::Preserve`])
+    link_target: Option<String>,
+
+    /// The content of a file, which is read from an archive
+    data: Option<Arc<[u8]>>,
+
+    /// Fs entries of the roots, which are merged into the entry. A file has one,
+    /// a dir has one per root with the same relative dir. The last one is [`Self::path`]
+    layers: Vec<Layer>,
+}
+
+/// Metadata of an entry, which is read from the disk or from an archive header
+#[derive(Debug, Clone, Default)]
+pub struct EntryMetadata {
+    len: u64,
+    accessed: Option<SystemTime>,
+    created: Option<SystemTime>,
+    modified: Option<SystemTime>,
+}
+
+impl EntryMetadata {
+    pub fn new(
+        len: u64,
+        accessed: Option<SystemTime>,
+        created: Option<SystemTime>,
+        modified: Option<SystemTime>,
+    ) -> Self {
+        Self {
+            len,
+            accessed,
+            created,
+            modified,
+        }
+    }
+
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn accessed(&self) -> Option<SystemTime> {
+        self.accessed
+    }
+
+    pub fn created(&self) -> Option<SystemTime> {
+        self.created
+    }
+
+    pub fn modified(&self) -> Option<SystemTime> {
+        self.modified
+    }
+}
+
+impl From<std::fs::Metadata> for EntryMetadata {
+    fn from(value: std::fs::Metadata) -> Self {
+        Self::new(
+            value.len(),
+            value.accessed().ok(),
+            value.created().ok(),
+            value.modified().ok(),
+        )
+    }
+}
+
+/// A root of embedded entries, see the `path` attribute
+#[derive(Debug)]
+pub enum Root {
+    Dir(PathBuf),
+
+    /// An archive, which is embedded as if it were a dir
+    Archive(Archive),
+}
+
+impl Root {
+    /// A dir is used as is, a file is read as an archive
+    pub fn open(path: PathBuf, symlinks: Symlinks) -> Result<Self, ReadArchiveError> {
+        if path.is_file() {
+            Archive::read(&path, symlinks).map(Self::Archive)
+        } else {
+            Ok(Self::Dir(path))
+        }
+    }
+
+    /// The absolute fs path of the root. Entries of an archive have virtual paths inside it
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Dir(path) => path,
+            Self::Archive(archive) => archive.path(),
+        }
+    }
+
+    pub fn archive(&self) -> Option<&Archive> {
+        match self {
+            Self::Dir(_) => None,
+            Self::Archive(archive) => Some(archive),
+        }
+    }
+}
+
+/// A fs entry of one of the roots
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layer {
+    /// The index of the root in the order of `path` attributes
+    pub root: usize,
+
+    /// The absolute fs path of the entry. It is virtual for an entry of an archive
+    pub origin: PathBuf,
+}
+
+/// Settings of [`FsInfo::read`]
+#[derive(Debug, Clone, Copy)]
+pub struct ReadOptions<'a> {
+    /// The roots, relative paths are built from their paths
+    pub roots: &'a [Root],
+    pub with_extension: WithExtension,
+    pub symlinks: Symlinks,
+    pub conflicts: Conflicts,
+}
+
+/// A child of a dir in one of the layers
+struct LayerChild {
+    kind: EntryKind,
+    path: PathBuf,
+    link_target: Option<String>,
+    archived: Option<ArchiveEntry>,
+}
+
+/// A child of one of the layers, which is not normalized yet
+struct LayerEntry {
+    child: LayerChild,
+    layers: Vec<Layer>,
 }
 
 impl FsInfo {
@@ -33,17 +176,59 @@ impl FsInfo {
         &self.path
     }
 
-    pub fn metadata(&self) -> &std::fs::Metadata {
+    pub fn metadata(&self) -> &EntryMetadata {
         &self.metadata
     }
 
+    pub fn link_target(&self) -> Option<&str> {
+        self.link_target.as_deref()
+    }
+
+    /// The index of the root, which the entry is read from
+    pub fn root(&self) -> usize {
+        self.layers.last().map(|l| l.root).unwrap_or_default()
+    }
+
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
+    /// The content, which is not read from the origin path: the target of a preserved symlink
+    /// or the content of an archived file. Such a file may not be read at runtime in dev mode
+    pub fn inline_content(&self) -> Option<&[u8]> {
+        self.link_target
+            .as_deref()
+            .map(str::as_bytes)
+            .or(self.data.as_deref())
+    }
+
+    /// Opens the content of the file, see [`FsInfo::inline_content`]
+    pub fn open(&self) -> io::Result<Box<dyn Read + '_>> {
+        match self.inline_content() {
+            Some(content) => Ok(Box::new(content)),
+            None => Ok(Box::new(BufReader::new(File::open(
+                self.path.origin_path(),
+            )?))),
+        }
+    }
+
+    /// Reads the whole content of the file, see [`FsInfo::open`]
+    pub fn read_content(&self) -> io::Result<Vec<u8>> {
+        let mut content = Vec::new();
+        self.open()?.read_to_end(&mut content)?;
+        Ok(content)
+    }
+
+    /// Creates the root dir, which merges all the roots. The last one is its path
     pub fn root_entry(
-        path: &Path,
+        roots: &[Root],
         main_ident: Ident,
     ) -> Result<Entry<FsInfo>, CreateRootEntryError> {
+        let path = roots.last().ok_or(CreateRootEntryError::NoRoots)?.path();
         let metadata = path
             .metadata()
-            .map_err(CreateRootEntryError::UnabeToReadMetadata)?;
+            .map_err(CreateRootEntryError::UnabeToReadMetadata)?
+            .into();
         let path = EntryPath {
             origin: path
                 .to_str()
@@ -54,15 +239,130 @@ impl FsInfo {
             file_name: String::new(),
             file_stem: String::new(),
         };
-        Ok(Entry::Dir(FsInfo { path, metadata }))
+        let layers = roots
+            .iter()
+            .enumerate()
+            .map(|(idx, root)| Layer {
+                root: idx,
+                origin: root.path().to_path_buf(),
+            })
+            .collect();
+        Ok(Entry::Dir(FsInfo {
+            path,
+            metadata,
+            link_target: None,
+            data: None,
+            layers,
+        }))
     }
 
+    /// Reads children of the dir and merges children of its layers. An entry of a later layer
+    /// shadows an entry of an earlier one with the same name, dirs with the same name are merged
+    ///
+    /// # Arguments
+    ///
+    /// * `layers` - fs dirs of the roots, which are merged into the dir
+    /// * `ancestors` - canonical paths of the dir and its parents, a followed symlink to one of them is a cycle
+    /// * `ignore_files` - ignore files of the dir and its parents, matched entries are skipped
     pub fn read(
-        path: &Path,
-        root: &Path,
-        with_extension: WithExtension,
+        layers: &[Layer],
+        options: ReadOptions<'_>,
+        ancestors: &[PathBuf],
+        ignore_files: &IgnoreFiles,
         idents: &mut UniqueIdents,
     ) -> Result<Vec<Entry<FsInfo>>, ReadEntriesError> {
+        let mut entries: Vec<LayerEntry> = Vec::new();
+        let mut by_name: HashMap<OsString, usize> = HashMap::new();
+
+        for layer in layers {
+            let children = match options.roots[layer.root].archive() {
+                Some(archive) => Self::read_archive_layer(archive, &layer.origin)?,
+                None => Self::read_layer(&layer.origin, options.symlinks, ancestors)?,
+            };
+            for child in children {
+                if ignore_files.is_ignored(&child.path, child.kind == EntryKind::Dir) {
+                    continue;
+                }
+
+                let layer = Layer {
+                    root: layer.root,
+                    origin: child.path.clone(),
+                };
+                let name = child.path.file_name().unwrap_or_default().to_owned();
+                let Some(&idx) = by_name.get(&name) else {
+                    by_name.insert(name, entries.len());
+                    entries.push(LayerEntry {
+                        child,
+                        layers: vec![layer],
+                    });
+                    continue;
+                };
+
+                let prev = &mut entries[idx];
+                let merge = child.kind == EntryKind::Dir && prev.child.kind == EntryKind::Dir;
+                if !merge && options.conflicts == Conflicts::Error {
+                    return Err(ReadEntriesError::Conflict(
+                        prev.child.path.clone(),
+                        child.path,
+                    ));
+                }
+
+                if merge {
+                    prev.layers.push(layer);
+                } else {
+                    prev.layers = vec![layer];
+                }
+                prev.child = child;
+            }
+        }
+
+        entries.sort_by(|a, b| {
+            (a.child.kind, a.child.path.file_name()).cmp(&(b.child.kind, b.child.path.file_name()))
+        });
+
+        entries
+            .into_iter()
+            .map(|LayerEntry { child, layers }| {
+                let (metadata, data) = match child.archived {
+                    Some(archived) => (
+                        archived.metadata,
+                        (child.kind == EntryKind::File).then_some(archived.data),
+                    ),
+                    None => {
+                        let metadata = match child.link_target {
+                            Some(_) => child.path.symlink_metadata(),
+                            None => child.path.metadata(),
+                        }
+                        .map_err(ReadEntriesError::UnabeToReadMetadata)?;
+                        (metadata.into(), None)
+                    }
+                };
+                let root = layers.last().map(|l| l.root).unwrap_or_default();
+                let entry_path = EntryPath::normalize(
+                    child.path,
+                    options.roots[root].path(),
+                    options.with_extension,
+                    idents,
+                )
+                .map_err(ReadEntriesError::UnableToNormalizeEntryPath)?;
+                Ok(Self::from_kind(
+                    child.kind,
+                    entry_path,
+                    metadata,
+                    child.link_target,
+                    data,
+                    layers,
+                ))
+            })
+            .collect::<Result<Vec<_>, ReadEntriesError>>()
+    }
+
+    /// Reads children of one dir: kinds, paths and targets of preserved links
+    fn read_layer(
+        path: &Path,
+        symlinks: Symlinks,
+        ancestors: &[PathBuf],
+    ) -> Result<Vec<LayerChild>, ReadEntriesError> {
         let dir = read_dir(path).map_err(ReadEntriesError::UnabeToReadDir)?;
 
         let mut entries = Vec::new();
@@ -70,36 +370,106 @@ impl FsInfo {
         for entry in dir {
             let entry = entry.map_err(ReadEntriesError::UnabeToReadEntry)?;
             let path = entry.path();
-            if path.is_dir() {
-                entries.push((EntryKind::Dir, path));
+            let is_symlink = entry
+                .file_type()
+                .map_err(ReadEntriesError::UnabeToReadEntry)?
+                .is_symlink();
+
+            let mut link_target = None;
+            if is_symlink {
+                match symlinks {
+                    Symlinks::Follow => {}
+                    Symlinks::Skip => continue,
+                    Symlinks::Error => return Err(ReadEntriesError::Symlink(path)),
+                    Symlinks::Preserve => {
+                        let target = std::fs::read_link(&path)
+                            .map_err(ReadEntriesError::UnableToReadLink)?;
+                        let target = target
+                            .to_str()
+                            .ok_or_else(|| ReadEntriesError::LinkTargetNotUtf8(path.clone()))?
+                            .to_owned();
+                        link_target = Some(target);
+                    }
+                }
+            }
+
+            let kind = if link_target.is_some() {
+                EntryKind::File
+            } else if path.is_dir() {
+                if is_symlink {
+                    let target =
+                        std::fs::canonicalize(&path).map_err(ReadEntriesError::UnableToReadLink)?;
+                    if ancestors.contains(&target) {
+                        return Err(ReadEntriesError::SymlinkCycle(path));
+                    }
+                }
+                EntryKind::Dir
             } else if path.is_file() {
-                entries.push((EntryKind::File, path));
+                EntryKind::File
             } else {
                 continue;
             };
+
+            entries.push(LayerChild {
+                kind,
+                path,
+                link_target,
+                archived: None,
+            });
         }
 
-        entries.sort();
+        Ok(entries)
+    }
 
-        entries
-            .into_iter()
-            .map(|(kind, path)| {
-                let metadata = path
-                    .metadata()
-                    .map_err(ReadEntriesError::UnabeToReadMetadata)?;
-                let entry_path = EntryPath::normalize(path, root, with_extension, idents)
-                    .map_err(ReadEntriesError::UnableToNormalizeEntryPath)?;
-                Ok(Self::from_kind(kind, entry_path, metadata))
+    /// Reads children of a dir inside an archive, `path` is its virtual path
+    fn read_archive_layer(
+        archive: &Archive,
+        path: &Path,
+    ) -> Result<Vec<LayerChild>, ReadEntriesError> {
+        let relative = path
+            .strip_prefix(archive.path())
+            .map_err(|_| {
+                ReadEntriesError::UnableToNormalizeEntryPath(
+                    NormalizePathError::UnableToStripRootPrefix(UnableToStripRootPrefix {
+                        origin: path.to_path_buf(),
+                        root: archive.path().to_path_buf(),
+                    }),
+                )
+            })?
+            .components()
+            .filter_map(|c| match c {
+                Component::Normal(name) => name.to_str(),
+                _ => None,
             })
-            .collect::<Result<Vec<_>, ReadEntriesError>>()
+            .collect::<Vec<_>>()
+            .join("/");
+
+        Ok(archive
+            .children(&relative)
+            .map(|(name, entry)| LayerChild {
+                kind: entry.kind,
+                path: path.join(name),
+                link_target: None,
+                archived: Some(entry.clone()),
+            })
+            .collect())
     }
 
     pub fn from_kind(
         kind: EntryKind,
         path: EntryPath,
-        metadata: std::fs::Metadata,
+        metadata: EntryMetadata,
+        link_target: Option<String>,
+        data: Option<Arc<[u8]>>,
+        layers: Vec<Layer>,
     ) -> Entry<FsInfo> {
-        let info = Self { path, metadata };
+        let info = Self {
+            path,
+            metadata,
+            link_target,
+            data,
+            layers,
+        };
         match kind {
             EntryKind::Dir => Entry::Dir(info),
             EntryKind::File => Entry::File(info),
@@ -109,6 +479,7 @@ impl FsInfo {
 
 #[derive(Debug)]
 pub enum CreateRootEntryError {
+    NoRoots,
     NotUtf8,
     UnabeToReadMetadata(io::Error),
 }
@@ -126,6 +497,21 @@ pub enum ReadEntriesError {
 
     #[display("unable to read a metadata: {_0}")]
     UnabeToReadMetadata(io::Error),
+
+    #[display("symlinks are not allowed, but {_0:?} is a symlink")]
+    Symlink(PathBuf),
+
+    #[display("symlink {_0:?} points to one of its parent dirs")]
+    SymlinkCycle(PathBuf),
+
+    #[display("unable to read a symlink: {_0}")]
+    UnableToReadLink(io::Error),
+
+    #[display("the target of symlink {_0:?} is not a valid utf8 string")]
+    LinkTargetNotUtf8(PathBuf),
+
+    #[display("{_1:?} shadows {_0:?} of a previous root, but `conflicts` is `error`")]
+    Conflict(PathBuf, PathBuf),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -238,7 +624,7 @@ impl EntryIdent {
         StrIdent::new(candidate)
     }
 
-    fn create(str: &str, idents: &mut UniqueIdents) -> Result<EntryIdent, EmptryString> {
+    pub fn create(str: &str, idents: &mut UniqueIdents) -> Result<EntryIdent, EmptryString> {
         let mut ident_key = String::with_capacity(str.len() + 1);
 
         let mut chars = str.chars().peekable();
@@ -362,43 +748,17 @@ pub fn expand_and_canonicalize(
     input: &str,
     get_env: impl Fn(&str) -> Result<String, VarError>,
 ) -> Result<PathBuf, ExpandPathError> {
-    let re = Regex::new(r"\$(\w+)|\$\{([^}]+)\}").unwrap();
-
-    let replacement = |caps: &Captures| -> Result<String, ExpandPathError> {
-        let var = caps
-            .get(1)
-            .or_else(|| caps.get(2))
-            .map(|m| m.as_str())
-            .expect("Unable to find variable");
-        let value = get_env(var).map_err(|e| ExpandPathError::Env(var.to_owned(), e))?;
-        Ok(value)
-    };
-    let path = replace_all(&re, input, replacement)?;
-
-    let path = if std::path::MAIN_SEPARATOR == '\\' {
-        path.replace("/", std::path::MAIN_SEPARATOR_STR)
-    } else {
-        path.replace("\\", std::path::MAIN_SEPARATOR_STR)
-    };
-
+    let path = expand(input, get_env)?;
     std::fs::canonicalize(&path).map_err(|e| ExpandPathError::Canonicalize(path.clone(), e))
 }
 
-pub fn replace_all<E>(
-    re: &Regex,
-    haystack: &str,
-    replacement: impl Fn(&Captures) -> Result<String, E>,
-) -> Result<String, E> {
-    let mut new = String::with_capacity(haystack.len());
-    let mut last_match = 0;
-    for caps in re.captures_iter(haystack) {
-        let m = caps.get(0).unwrap();
-        new.push_str(&haystack[last_match..m.start()]);
-        new.push_str(&replacement(&caps)?);
-        last_match = m.end();
-    }
-    new.push_str(&haystack[last_match..]);
-    Ok(new)
+/// Replaces environment variables (`$VAR` or `${VAR}`) and separators, but does not require the path to exist.
+/// `embed_it_build` expands paths with the same function
+pub fn expand(
+    input: &str,
+    get_env: impl Fn(&str) -> Result<String, VarError>,
+) -> Result<String, ExpandPathError> {
+    embed_it_utils::expand::expand(input, get_env).map_err(|e| ExpandPathError::Env(e.var, e.error))
 }
 
 #[derive(Debug, derive_more::Display)]
@@ -434,7 +794,7 @@ mod tests {
 
     use pretty_assertions::assert_eq;
 
-    use super::{EntryPath, ExpandPathError, ReadEntriesError, expand_and_canonicalize};
+    use super::{EntryPath, ExpandPathError, ReadEntriesError, expand, expand_and_canonicalize};
 
     fn entry_path<P: AsRef<Path>>(
         origin: P,
@@ -788,6 +1148,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn expand_not_existing() {
+        let res = expand("$DIR/not_existing/${NAME}.json", |var| match var {
+            "DIR" => Ok("/tmp".to_owned()),
+            "NAME" => Ok("assets".to_owned()),
+            _ => panic!("unknown variable: '{var}'"),
+        })
+        .unwrap_or_else(|e| panic!("Unable to expand '{e:#?}'"));
+
+        assert_eq!(
+            PathBuf::from(res),
+            Path::new("/tmp").join("not_existing").join("assets.json")
+        );
+    }
+
     #[test]
     #[cfg(target_os = "linux")]
     fn expand_and_canonicalize_not_utf() {
@@ -849,6 +1224,75 @@ mod tests {
         assert_eq!(&str, "unable to normalize a path: empty path");
     }
 
+    #[test]
+    #[cfg(feature = "ignore")]
+    fn read_respects_ignore_files() {
+        use crate::{
+            embed::attributes::{conflicts::Conflicts, symlinks::Symlinks},
+            test_helpers::{create_dir_all, create_file, remove_and_create_dir_all},
+        };
+
+        use super::{FsInfo, IgnoreFiles, Layer, ReadOptions, Root};
+
+        let root = tests_dir().join(fn_name!());
+        remove_and_create_dir_all(&root);
+        let root = std::fs::canonicalize(&root).unwrap();
+        create_file(root.join(".gitignore"), b"*.swp\n!keep.swp\n/build/\n");
+        create_file(root.join(".ignore"), b"tmp/\n");
+        create_file(root.join("a.txt"), b"a");
+        create_file(root.join("a.txt.swp"), b"a");
+        create_file(root.join("keep.swp"), b"keep");
+        create_dir_all(root.join("build"));
+        create_dir_all(root.join("tmp"));
+
+        let nested = root.join("nested");
+        create_dir_all(nested.join("build"));
+        create_file(nested.join(".gitignore"), b"!b.swp\nc.txt\n");
+        create_file(nested.join("b.swp"), b"b");
+        create_file(nested.join("c.txt"), b"c");
+        create_file(nested.join("d.swp"), b"d");
+
+        let roots = [Root::Dir(root.clone())];
+        let options = ReadOptions {
+            roots: &roots,
+            with_extension: WithExtension::Yes,
+            symlinks: Symlinks::Follow,
+            conflicts: Conflicts::Shadow,
+        };
+        let read = |dir: &Path, ignore_files: &IgnoreFiles| {
+            FsInfo::read(
+                &[Layer {
+                    root: 0,
+                    origin: dir.to_path_buf(),
+                }],
+                options,
+                &[],
+                ignore_files,
+                &mut UniqueIdents::default(),
+            )
+            .unwrap()
+            .into_iter()
+            .map(|e| e.value().path().relative.clone())
+            .collect::<Vec<_>>()
+        };
+
+        let root_ignore = IgnoreFiles::default().with_dir(&root).unwrap();
+        assert_eq!(
+            read(&root, &root_ignore),
+            vec!["nested", ".gitignore", ".ignore", "a.txt", "keep.swp"]
+        );
+
+        // a nested file negates a parent pattern, and an anchored pattern does not match deeper
+        let nested_ignore = root_ignore.with_dir(&nested).unwrap();
+        assert_eq!(
+            read(&nested, &nested_ignore),
+            vec!["nested/build", "nested/.gitignore", "nested/b.swp"]
+        );
+
+        // nothing is ignored without ignore files
+        assert_eq!(read(&nested, &IgnoreFiles::default()).len(), 5);
+    }
+
     #[test]
     fn normalize_path_error_display() {
         let str = NormalizePathError::NoName.to_string();
@@ -0,0 +1,443 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use embed_it_utils::entry::EntryKind;
+
+use crate::embed::attributes::symlinks::Symlinks;
+
+use super::EntryMetadata;
+
+/// A format of an archive, which is detected by the extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Tar,
+    TarGz,
+    Zip,
+}
+
+impl ArchiveFormat {
+    /// Detects the format by the extension: `.tar`, `.tar.gz`, `.tgz` or `.zip`
+    pub fn detect(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?.to_lowercase();
+        if name.ends_with(".tar") {
+            Some(Self::Tar)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(Self::TarGz)
+        } else if name.ends_with(".zip") {
+            Some(Self::Zip)
+        } else {
+            None
+        }
+    }
+
+    /// The feature, which is required to read the format
+    pub fn feature(&self) -> &'static str {
+        match self {
+            Self::Tar | Self::TarGz => "tar",
+            Self::Zip => "zip",
+        }
+    }
+}
+
+/// A file or a dir of an archive
+#[derive(Clone)]
+pub struct ArchiveEntry {
+    pub kind: EntryKind,
+    pub metadata: EntryMetadata,
+
+    /// The content of a file. Empty for a dir
+    pub data: Arc<[u8]>,
+}
+
+impl Debug for ArchiveEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArchiveEntry")
+            .field("kind", &self.kind)
+            .field("metadata", &self.metadata)
+            .field("data", &self.data.len())
+            .finish()
+    }
+}
+
+/// An archive, which is read into memory at macro time
+#[derive(Debug)]
+pub struct Archive {
+    path: PathBuf,
+
+    /// Entries by `/` separated relative paths. Every parent dir is present
+    entries: BTreeMap<String, ArchiveEntry>,
+}
+
+#[derive(Debug, derive_more::Display)]
+pub enum ReadArchiveError {
+    #[display("{_0:?} is not a tar or zip archive")]
+    UnknownFormat(PathBuf),
+
+    #[display("{_0:?} is a {} archive, which requires the `{}` feature", _1.feature(), _1.feature())]
+    FeatureRequired(PathBuf, ArchiveFormat),
+
+    #[display("unable to read the archive: {_0}")]
+    Io(std::io::Error),
+
+    #[cfg(feature = "zip")]
+    #[display("unable to read the archive: {_0}")]
+    Zip(zip::result::ZipError),
+
+    #[display("the archive entry {_0:?} is not a valid relative utf8 path")]
+    InvalidPath(PathBuf),
+
+    #[display("the archive entry {_0:?} is both a file and a dir")]
+    KindMismatch(String),
+
+    #[display("symlinks are not allowed, but the archive entry {_0:?} is a link")]
+    Link(PathBuf),
+}
+
+impl Archive {
+    /// Reads the archive. Only `symlinks = "error"` applies to links of an archive, they are skipped otherwise
+    pub fn read(path: &Path, symlinks: Symlinks) -> Result<Self, ReadArchiveError> {
+        let format = ArchiveFormat::detect(path)
+            .ok_or_else(|| ReadArchiveError::UnknownFormat(path.to_path_buf()))?;
+
+        let mut archive = Self {
+            path: path.to_path_buf(),
+            entries: BTreeMap::new(),
+        };
+        archive.read_entries(format, symlinks)?;
+        Ok(archive)
+    }
+
+    #[cfg_attr(not(any(feature = "tar", feature = "zip")), allow(unused_variables))]
+    fn read_entries(
+        &mut self,
+        format: ArchiveFormat,
+        symlinks: Symlinks,
+    ) -> Result<(), ReadArchiveError> {
+        match format {
+            #[cfg(feature = "tar")]
+            ArchiveFormat::Tar => self.read_tar(self.open()?, symlinks),
+            #[cfg(feature = "tar")]
+            ArchiveFormat::TarGz => {
+                self.read_tar(flate2::read::GzDecoder::new(self.open()?), symlinks)
+            }
+            #[cfg(not(feature = "tar"))]
+            ArchiveFormat::Tar | ArchiveFormat::TarGz => {
+                Err(ReadArchiveError::FeatureRequired(self.path.clone(), format))
+            }
+            #[cfg(feature = "zip")]
+            ArchiveFormat::Zip => self.read_zip(self.open()?, symlinks),
+            #[cfg(not(feature = "zip"))]
+            ArchiveFormat::Zip => Err(ReadArchiveError::FeatureRequired(self.path.clone(), format)),
+        }
+    }
+
+    /// A link entry is an error with `symlinks = "error"` and is skipped otherwise
+    #[cfg(any(feature = "tar", feature = "zip"))]
+    fn check_link(path: &Path, symlinks: Symlinks) -> Result<(), ReadArchiveError> {
+        match symlinks {
+            Symlinks::Error => Err(ReadArchiveError::Link(path.to_path_buf())),
+            Symlinks::Follow | Symlinks::Skip | Symlinks::Preserve => Ok(()),
+        }
+    }
+
+    #[cfg(any(feature = "tar", feature = "zip"))]
+    fn open(&self) -> Result<std::fs::File, ReadArchiveError> {
+        std::fs::File::open(&self.path).map_err(ReadArchiveError::Io)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Direct children of the dir with `/` separated relative path (empty for the root)
+    pub fn children<'a>(
+        &'a self,
+        dir: &'a str,
+    ) -> impl Iterator<Item = (&'a str, &'a ArchiveEntry)> + 'a {
+        self.entries.iter().filter_map(move |(path, entry)| {
+            let name = if dir.is_empty() {
+                path.as_str()
+            } else {
+                path.strip_prefix(dir)?.strip_prefix('/')?
+            };
+            (!name.contains('/')).then_some((name, entry))
+        })
+    }
+
+    /// Adds the entry and its parent dirs, which may be omitted in the archive.
+    /// A later entry with the same path and kind replaces the earlier one, as on extraction
+    #[cfg(any(feature = "tar", feature = "zip"))]
+    fn insert(&mut self, path: &Path, entry: ArchiveEntry) -> Result<(), ReadArchiveError> {
+        use std::path::Component;
+
+        let mut components = Vec::new();
+        for component in path.components() {
+            match component {
+                Component::Normal(name) => components.push(
+                    name.to_str()
+                        .ok_or_else(|| ReadArchiveError::InvalidPath(path.to_path_buf()))?,
+                ),
+                Component::CurDir => {}
+                _ => return Err(ReadArchiveError::InvalidPath(path.to_path_buf())),
+            }
+        }
+        if components.is_empty() {
+            return Ok(());
+        }
+
+        for len in 1..components.len() {
+            let parent = components[..len].join("/");
+            let dir = self
+                .entries
+                .entry(parent.clone())
+                .or_insert_with(|| ArchiveEntry {
+                    kind: EntryKind::Dir,
+                    metadata: EntryMetadata::default(),
+                    data: Arc::new([]),
+                });
+            if dir.kind != EntryKind::Dir {
+                return Err(ReadArchiveError::KindMismatch(parent));
+            }
+        }
+
+        let relative = components.join("/");
+        match self.entries.get_mut(&relative) {
+            // a dir may be added as a parent before its own header
+            Some(prev) if prev.kind == entry.kind => {
+                *prev = entry;
+            }
+            Some(_) => return Err(ReadArchiveError::KindMismatch(relative)),
+            None => {
+                self.entries.insert(relative, entry);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads regular files and dirs, links are checked with [`Self::check_link`] and other entries are skipped
+    #[cfg(feature = "tar")]
+    fn read_tar(
+        &mut self,
+        reader: impl std::io::Read,
+        symlinks: Symlinks,
+    ) -> Result<(), ReadArchiveError> {
+        use std::{
+            io::Read,
+            time::{Duration, SystemTime},
+        };
+
+        let mut tar = tar::Archive::new(reader);
+        for entry in tar.entries().map_err(ReadArchiveError::Io)? {
+            let mut entry = entry.map_err(ReadArchiveError::Io)?;
+            let entry_type = entry.header().entry_type();
+            let path = entry.path().map_err(ReadArchiveError::Io)?.into_owned();
+            let kind = if entry_type.is_dir() {
+                EntryKind::Dir
+            } else if entry_type.is_file() {
+                EntryKind::File
+            } else {
+                if entry_type.is_symlink() || entry_type.is_hard_link() {
+                    Self::check_link(&path, symlinks)?;
+                }
+                continue;
+            };
+
+            let modified = entry
+                .header()
+                .mtime()
+                .ok()
+                .map(|secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs));
+            let mut data = Vec::new();
+            entry.read_to_end(&mut data).map_err(ReadArchiveError::Io)?;
+
+            let entry = ArchiveEntry {
+                kind,
+                metadata: EntryMetadata::new(data.len() as u64, None, None, modified),
+                data: data.into(),
+            };
+            self.insert(&path, entry)?;
+        }
+        Ok(())
+    }
+
+    /// Reads files and dirs, the modification time is taken as UTC. Links are checked with [`Self::check_link`]
+    #[cfg(feature = "zip")]
+    fn read_zip(
+        &mut self,
+        reader: impl std::io::Read + std::io::Seek,
+        symlinks: Symlinks,
+    ) -> Result<(), ReadArchiveError> {
+        use std::io::Read;
+
+        let mut zip = zip::ZipArchive::new(reader).map_err(ReadArchiveError::Zip)?;
+        for i in 0..zip.len() {
+            let mut file = zip.by_index(i).map_err(ReadArchiveError::Zip)?;
+            let path = file
+                .enclosed_name()
+                .ok_or_else(|| ReadArchiveError::InvalidPath(PathBuf::from(file.name())))?;
+            if file.is_symlink() {
+                Self::check_link(&path, symlinks)?;
+                continue;
+            }
+            let kind = if file.is_dir() {
+                EntryKind::Dir
+            } else {
+                EntryKind::File
+            };
+            let modified = file.last_modified().map(zip_time);
+            let mut data = Vec::new();
+            file.read_to_end(&mut data).map_err(ReadArchiveError::Io)?;
+
+            let entry = ArchiveEntry {
+                kind,
+                metadata: EntryMetadata::new(data.len() as u64, None, None, modified),
+                data: data.into(),
+            };
+            self.insert(&path, entry)?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts the MS-DOS time of a zip entry, which has no time zone, as UTC
+#[cfg(feature = "zip")]
+fn zip_time(time: zip::DateTime) -> std::time::SystemTime {
+    use std::time::{Duration, SystemTime};
+
+    // days from the civil date, see http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let (year, month, day) = (
+        i64::from(time.year()),
+        i64::from(time.month()),
+        i64::from(time.day()),
+    );
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    let secs = days * 86400
+        + i64::from(time.hour()) * 3600
+        + i64::from(time.minute()) * 60
+        + i64::from(time.second());
+
+    // zip dates start at 1980, so they are never before the epoch
+    SystemTime::UNIX_EPOCH + Duration::from_secs(secs as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::ArchiveFormat;
+
+    #[cfg(feature = "tar")]
+    fn read_tar(
+        append: impl FnOnce(&mut tar::Builder<Vec<u8>>),
+        symlinks: super::Symlinks,
+    ) -> Result<super::Archive, super::ReadArchiveError> {
+        let mut builder = tar::Builder::new(Vec::new());
+        append(&mut builder);
+        let data = builder.into_inner().unwrap();
+
+        let mut archive = super::Archive {
+            path: "assets.tar".into(),
+            entries: Default::default(),
+        };
+        archive.read_tar(&data[..], symlinks)?;
+        Ok(archive)
+    }
+
+    #[cfg(feature = "tar")]
+    fn append_file(builder: &mut tar::Builder<Vec<u8>>, path: &str, data: &[u8]) {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_cksum();
+        builder.append_data(&mut header, path, data).unwrap();
+    }
+
+    #[cfg(feature = "tar")]
+    fn append_link(builder: &mut tar::Builder<Vec<u8>>, path: &str, target: &str) {
+        let mut header = tar::Header::new_gnu();
+        header.set_entry_type(tar::EntryType::Symlink);
+        header.set_size(0);
+        builder.append_link(&mut header, path, target).unwrap();
+    }
+
+    #[test]
+    fn detect() {
+        let cases = [
+            ("a.tar", Some(ArchiveFormat::Tar)),
+            ("a.tar.gz", Some(ArchiveFormat::TarGz)),
+            ("A.TGZ", Some(ArchiveFormat::TarGz)),
+            ("a.zip", Some(ArchiveFormat::Zip)),
+            ("a.gz", None),
+            ("zip", None),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(ArchiveFormat::detect(Path::new(path)), expected, "{path}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "zip")]
+    fn zip_time() {
+        let time = zip::DateTime::from_date_and_time(2024, 2, 29, 13, 14, 16).unwrap();
+        let secs = super::zip_time(time)
+            .duration_since(std::time::SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        assert_eq!(secs, 1709212456);
+    }
+
+    #[test]
+    #[cfg(feature = "tar")]
+    fn duplicate_entry_later_wins() {
+        let archive = read_tar(
+            |builder| {
+                append_file(builder, "dir/hello.txt", b"hello");
+                append_file(builder, "./dir/hello.txt", b"world");
+            },
+            super::Symlinks::Follow,
+        )
+        .unwrap();
+        let children = archive.children("dir").collect::<Vec<_>>();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].0, "hello.txt");
+        assert_eq!(&children[0].1.data[..], b"world");
+
+        let err = read_tar(
+            |builder| {
+                append_file(builder, "dir", b"hello");
+                append_file(builder, "dir/hello.txt", b"hello");
+            },
+            super::Symlinks::Follow,
+        )
+        .unwrap_err();
+        assert!(matches!(err, super::ReadArchiveError::KindMismatch(p) if p == "dir"));
+    }
+
+    #[test]
+    #[cfg(feature = "tar")]
+    fn links() {
+        let append = |builder: &mut tar::Builder<Vec<u8>>| {
+            append_file(builder, "hello.txt", b"hello");
+            append_link(builder, "link.txt", "hello.txt");
+        };
+
+        let archive = read_tar(append, super::Symlinks::Skip).unwrap();
+        let names = archive.children("").map(|(n, _)| n).collect::<Vec<_>>();
+        assert_eq!(names, ["hello.txt"]);
+
+        let err = read_tar(append, super::Symlinks::Error).unwrap_err();
+        assert!(
+            matches!(&err, super::ReadArchiveError::Link(p) if p == Path::new("link.txt")),
+            "{err:?}"
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use std::path::Path;
+
+#[cfg(feature = "ignore")]
+use std::sync::Arc;
+
+#[cfg(feature = "ignore")]
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+
+/// The names of ignore files in the order of increasing precedence
+#[cfg(feature = "ignore")]
+const IGNORE_FILES: &[&str] = &[".gitignore", ".ignore"];
+
+/// Ignore files (`.gitignore` and `.ignore`) of a dir and its parents inside the embedded root.
+/// It is empty, if `respect_ignore_files` is disabled
+#[derive(Debug, Clone, Default)]
+pub struct IgnoreFiles {
+    /// Matchers from the root to the deepest dir
+    #[cfg(feature = "ignore")]
+    matchers: Vec<Arc<Gitignore>>,
+}
+
+#[derive(Debug, derive_more::Display)]
+pub enum ReadIgnoreFilesError {
+    #[cfg(feature = "ignore")]
+    #[display("{_0}")]
+    Ignore(ignore::Error),
+}
+
+impl IgnoreFiles {
+    /// Adds ignore files of the `dir`, which is a child of one of the added ones
+    #[cfg(feature = "ignore")]
+    pub fn with_dir(&self, dir: &Path) -> Result<Self, ReadIgnoreFilesError> {
+        let mut builder = GitignoreBuilder::new(dir);
+        for name in IGNORE_FILES {
+            let file = dir.join(name);
+            if file.is_file() {
+                if let Some(e) = builder.add(file) {
+                    return Err(ReadIgnoreFilesError::Ignore(e));
+                }
+            }
+        }
+        let matcher = builder.build().map_err(ReadIgnoreFilesError::Ignore)?;
+
+        let mut matchers = self.matchers.clone();
+        if !matcher.is_empty() {
+            matchers.push(Arc::new(matcher));
+        }
+        Ok(Self { matchers })
+    }
+
+    #[cfg(not(feature = "ignore"))]
+    pub fn with_dir(&self, _dir: &Path) -> Result<Self, ReadIgnoreFilesError> {
+        Ok(Self::default())
+    }
+
+    /// A deeper ignore file wins, so a nested one may negate a pattern of a parent.
+    /// Ignore files of other roots (see `path` overlays) are not applied
+    #[cfg(feature = "ignore")]
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        self.matchers
+            .iter()
+            .rev()
+            .filter(|m| path.starts_with(m.path()))
+            .map(|m| m.matched(path, is_dir))
+            .find(|m| !m.is_none())
+            .is_some_and(|m| m.is_ignore())
+    }
+
+    #[cfg(not(feature = "ignore"))]
+    pub fn is_ignored(&self, _path: &Path, _is_dir: bool) -> bool {
+        false
+    }
+}
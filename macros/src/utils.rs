@@ -1,3 +1,5 @@
 pub mod anymap;
+#[cfg(feature = "sha2")]
+pub mod base64;
 pub mod entry_ext;
 pub mod unique_names;
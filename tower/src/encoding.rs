@@ -0,0 +1,204 @@
+use http::{HeaderValue, header::GetAll};
+
+/// A content coding of a response
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    Zstd,
+    Brotli,
+    Gzip,
+    Identity,
+}
+
+impl Encoding {
+    /// All encodings in the order of the server preference
+    pub const ALL: [Self; 4] = [Self::Zstd, Self::Brotli, Self::Gzip, Self::Identity];
+
+    /// The token in `Accept-Encoding` and `Content-Encoding` headers
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Zstd => "zstd",
+            Self::Brotli => "br",
+            Self::Gzip => "gzip",
+            Self::Identity => "identity",
+        }
+    }
+}
+
+/// The qualities of encodings in thousandths, which are requested with `Accept-Encoding`
+#[derive(Debug, Default)]
+pub(crate) struct AcceptEncoding {
+    qualities: Vec<(String, u16)>,
+}
+
+/// `identity` is acceptable if it is not mentioned, but any mentioned encoding is preferred
+const IMPLICIT_IDENTITY_QUALITY: u16 = 1;
+
+impl AcceptEncoding {
+    /// Parses all `Accept-Encoding` headers of a request, invalid items are skipped
+    pub fn parse(headers: GetAll<'_, HeaderValue>) -> Self {
+        let qualities = headers
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|v| v.split(','))
+            .filter_map(|item| {
+                let mut parts = item.split(';');
+                let coding = parts.next()?.trim();
+                if coding.is_empty() {
+                    return None;
+                }
+
+                let mut quality = 1000;
+                for param in parts {
+                    let (name, value) = param.split_once('=')?;
+                    if name.trim().eq_ignore_ascii_case("q") {
+                        quality = parse_quality(value.trim())?;
+                    }
+                }
+                Some((coding.to_ascii_lowercase(), quality))
+            })
+            .collect();
+        Self { qualities }
+    }
+
+    fn explicit(&self, coding: &str) -> Option<u16> {
+        self.qualities
+            .iter()
+            .find(|(c, _)| c == coding)
+            .map(|(_, q)| *q)
+    }
+
+    /// The quality of the encoding, `0` means it is not acceptable
+    pub fn quality(&self, encoding: Encoding) -> u16 {
+        self.explicit(encoding.as_str())
+            .or_else(|| match encoding {
+                // `x-gzip` is an alias of `gzip`
+                Encoding::Gzip => self.explicit("x-gzip"),
+                _ => None,
+            })
+            .or_else(|| self.explicit("*"))
+            .unwrap_or(match encoding {
+                Encoding::Identity => IMPLICIT_IDENTITY_QUALITY,
+                _ => 0,
+            })
+    }
+
+    /// Chooses the acceptable encoding with the highest quality, for which `content` returns a value.
+    /// The order of [`Encoding::ALL`] breaks ties. If nothing is acceptable, the identity is used
+    pub fn negotiate(
+        &self,
+        identity: &'static [u8],
+        content: impl Fn(Encoding) -> Option<&'static [u8]>,
+    ) -> (Encoding, &'static [u8]) {
+        let mut best: Option<(u16, Encoding, &'static [u8])> = None;
+        for encoding in Encoding::ALL {
+            let quality = self.quality(encoding);
+            if quality == 0 || best.is_some_and(|(q, ..)| q >= quality) {
+                continue;
+            }
+
+            let content = match encoding {
+                Encoding::Identity => Some(identity),
+                _ => content(encoding),
+            };
+            if let Some(content) = content {
+                best = Some((quality, encoding, content));
+            }
+        }
+        best.map_or((Encoding::Identity, identity), |(_, e, c)| (e, c))
+    }
+}
+
+/// Parses a quality value `0`, `1` or `0.xxx` into thousandths
+fn parse_quality(value: &str) -> Option<u16> {
+    let (int, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if fraction.len() > 3 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let fraction = format!("{fraction:0<3}").parse::<u16>().ok()?;
+    match int {
+        "0" => Some(fraction),
+        "1" if fraction == 0 => Some(1000),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{HeaderMap, HeaderValue, header::ACCEPT_ENCODING};
+    use pretty_assertions::assert_eq;
+
+    use super::{AcceptEncoding, Encoding, parse_quality};
+
+    fn accept(values: &[&'static str]) -> AcceptEncoding {
+        let mut headers = HeaderMap::new();
+        for v in values {
+            headers.append(ACCEPT_ENCODING, HeaderValue::from_static(v));
+        }
+        AcceptEncoding::parse(headers.get_all(ACCEPT_ENCODING))
+    }
+
+    #[test]
+    fn quality() {
+        assert_eq!(parse_quality("1"), Some(1000));
+        assert_eq!(parse_quality("1.000"), Some(1000));
+        assert_eq!(parse_quality("0"), Some(0));
+        assert_eq!(parse_quality("0.5"), Some(500));
+        assert_eq!(parse_quality("0.05"), Some(50));
+        assert_eq!(parse_quality("0.123"), Some(123));
+        assert_eq!(parse_quality("0.1234"), None);
+        assert_eq!(parse_quality("1.5"), None);
+        assert_eq!(parse_quality("2"), None);
+        assert_eq!(parse_quality("abc"), None);
+    }
+
+    #[test]
+    fn parse() {
+        let accept = accept(&["gzip;q=0.8, BR", "zstd ; q=0.9, deflate;q=bad"]);
+        assert_eq!(accept.quality(Encoding::Gzip), 800);
+        assert_eq!(accept.quality(Encoding::Brotli), 1000);
+        assert_eq!(accept.quality(Encoding::Zstd), 900);
+        assert_eq!(accept.quality(Encoding::Identity), 1);
+
+        let accept = super::AcceptEncoding::default();
+        assert_eq!(accept.quality(Encoding::Gzip), 0);
+        assert_eq!(accept.quality(Encoding::Identity), 1);
+    }
+
+    #[test]
+    fn wildcard() {
+        let accept = accept(&["*;q=0.5, gzip;q=0"]);
+        assert_eq!(accept.quality(Encoding::Zstd), 500);
+        assert_eq!(accept.quality(Encoding::Gzip), 0);
+        assert_eq!(accept.quality(Encoding::Identity), 500);
+    }
+
+    #[test]
+    fn negotiate() {
+        let all = |e: Encoding| Some(e.as_str().as_bytes());
+        assert_eq!(
+            accept(&["gzip, br, zstd"]).negotiate(b"raw", all),
+            (Encoding::Zstd, b"zstd".as_slice())
+        );
+        assert_eq!(
+            accept(&["gzip, br;q=0.5"]).negotiate(b"raw", all),
+            (Encoding::Gzip, b"gzip".as_slice())
+        );
+        assert_eq!(
+            accept(&["br, zstd"]).negotiate(b"raw", |e| (e == Encoding::Gzip).then_some(b"gzip")),
+            (Encoding::Identity, b"raw".as_slice())
+        );
+        assert_eq!(
+            accept(&["x-gzip"]).negotiate(b"raw", all),
+            (Encoding::Gzip, b"gzip".as_slice())
+        );
+        assert_eq!(
+            accept(&["identity;q=0, gzip;q=0"]).negotiate(b"raw", all),
+            (Encoding::Identity, b"raw".as_slice())
+        );
+        assert_eq!(
+            accept(&[]).negotiate(b"raw", all),
+            (Encoding::Identity, b"raw".as_slice())
+        );
+    }
+}
@@ -0,0 +1,9 @@
+#![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
+mod encoding;
+mod range;
+mod serve_dir;
+mod serve_file;
+
+pub use encoding::Encoding;
+pub use serve_dir::ServeDir;
+pub use serve_file::ServeFile;
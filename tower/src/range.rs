@@ -0,0 +1,241 @@
+use std::ops::Range;
+
+/// More ranges in a request are not served to avoid amplification, the whole content is sent instead.
+/// It is sent as well if the ranges are longer than the content in total
+const MAX_RANGES: usize = 32;
+
+/// The result of the `Range` header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Ranges {
+    /// The header is absent, invalid or ignored, so the whole content is sent
+    Full,
+
+    /// The satisfiable ranges sorted by the start, overlapping and adjacent ranges are coalesced,
+    /// so the parts are never longer than the content
+    Partial(Vec<Range<usize>>),
+
+    /// None of the ranges overlap the content
+    Unsatisfiable,
+}
+
+impl Ranges {
+    /// Parses a value of the `Range` header for a content of the length.
+    /// Only the `bytes` unit is supported, and a syntactically invalid value is ignored
+    pub fn parse(value: &str, len: usize) -> Self {
+        let Some((unit, specs)) = value.split_once('=') else {
+            return Self::Full;
+        };
+        if !unit.trim().eq_ignore_ascii_case("bytes") {
+            return Self::Full;
+        }
+
+        let mut ranges = Vec::new();
+        for spec in specs.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let Some((first, last)) = spec.split_once('-') else {
+                return Self::Full;
+            };
+            let (first, last) = (first.trim(), last.trim());
+            let range = match (parse_position(first), parse_position(last)) {
+                // `-n` is the last `n` bytes
+                (None, Some(suffix)) if first.is_empty() => {
+                    (suffix > 0 && len > 0).then(|| len.saturating_sub(suffix)..len)
+                }
+                (Some(first), None) if last.is_empty() => (first < len).then_some(first..len),
+                (Some(first), Some(last)) if first <= last => {
+                    (first < len).then(|| first..last.saturating_add(1).min(len))
+                }
+                _ => return Self::Full,
+            };
+            ranges.extend(range);
+            if ranges.len() > MAX_RANGES {
+                return Self::Full;
+            }
+        }
+
+        if ranges.is_empty() {
+            return Self::Unsatisfiable;
+        }
+        // overlapping ranges would send the same bytes many times, like a client
+        // could request the whole content once
+        let total = ranges.iter().map(ExactSizeIterator::len).sum::<usize>();
+        if total > len {
+            return Self::Full;
+        }
+        Self::Partial(coalesce(ranges))
+    }
+}
+
+/// Sorts ranges and merges overlapping and adjacent ones
+fn coalesce(mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    ranges.sort_by_key(|r| r.start);
+    let mut result: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match result.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => result.push(range),
+        }
+    }
+    result
+}
+
+fn parse_position(value: &str) -> Option<usize> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    // a position after the end is clamped
+    Some(value.parse::<usize>().unwrap_or(usize::MAX))
+}
+
+/// The value of the `Content-Range` header of a satisfied range
+pub(crate) fn content_range(range: &Range<usize>, len: usize) -> String {
+    format!("bytes {}-{}/{len}", range.start, range.end - 1)
+}
+
+/// Builds a `multipart/byteranges` body, returns the boundary and the body
+pub(crate) fn multipart(
+    content: &[u8],
+    ranges: &[Range<usize>],
+    content_type: &str,
+) -> (String, Vec<u8>) {
+    let boundary = boundary(content);
+    let mut body = Vec::with_capacity(ranges.iter().map(|r| r.len() + 128).sum());
+    for range in ranges {
+        body.extend_from_slice(b"\r\n--");
+        body.extend_from_slice(boundary.as_bytes());
+        body.extend_from_slice(b"\r\nContent-Type: ");
+        body.extend_from_slice(content_type.as_bytes());
+        body.extend_from_slice(b"\r\nContent-Range: ");
+        body.extend_from_slice(content_range(range, content.len()).as_bytes());
+        body.extend_from_slice(b"\r\n\r\n");
+        body.extend_from_slice(&content[range.clone()]);
+    }
+    body.extend_from_slice(b"\r\n--");
+    body.extend_from_slice(boundary.as_bytes());
+    body.extend_from_slice(b"--\r\n");
+    (boundary, body)
+}
+
+/// A boundary, which does not occur in the content
+fn boundary(content: &[u8]) -> String {
+    (0u32..)
+        .map(|n| format!("embed_it_byteranges_{n:08x}"))
+        .find(|b| !content.windows(b.len()).any(|w| w == b.as_bytes()))
+        .expect("the content cannot contain every boundary")
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{Ranges, boundary, content_range, multipart};
+
+    #[test]
+    fn single() {
+        assert_eq!(Ranges::parse("bytes=0-4", 10), Ranges::Partial(vec![0..5]));
+        assert_eq!(Ranges::parse("bytes=5-", 10), Ranges::Partial(vec![5..10]));
+        assert_eq!(Ranges::parse("bytes=-3", 10), Ranges::Partial(vec![7..10]));
+        assert_eq!(Ranges::parse("bytes=-30", 10), Ranges::Partial(vec![0..10]));
+        assert_eq!(
+            Ranges::parse("bytes=8-100", 10),
+            Ranges::Partial(vec![8..10])
+        );
+        assert_eq!(
+            Ranges::parse("BYTES = 1-1", 10),
+            Ranges::Partial(vec![1..2])
+        );
+        assert_eq!(
+            Ranges::parse("bytes=0-99999999999999999999999", 10),
+            Ranges::Partial(vec![0..10])
+        );
+    }
+
+    #[test]
+    fn multiple() {
+        assert_eq!(
+            Ranges::parse("bytes=0-1, 4-5,-2", 10),
+            Ranges::Partial(vec![0..2, 4..6, 8..10])
+        );
+        assert_eq!(
+            Ranges::parse("bytes=0-1, 20-30", 10),
+            Ranges::Partial(vec![0..2])
+        );
+        let many = (0..33)
+            .map(|i| format!("{}-{}", i * 2, i * 2))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            Ranges::parse(&format!("bytes={}", many.join(",")), 100),
+            Ranges::Full
+        );
+    }
+
+    #[test]
+    fn coalesced() {
+        assert_eq!(
+            Ranges::parse("bytes=6-8, 0-1, 2-3, 7-", 10),
+            Ranges::Partial(vec![0..4, 6..10])
+        );
+        assert_eq!(
+            Ranges::parse("bytes=0-5, 4-7", 10),
+            Ranges::Partial(vec![0..8])
+        );
+        let overlapping = vec!["0-"; 32].join(",");
+        assert_eq!(
+            Ranges::parse(&format!("bytes={overlapping}"), 10),
+            Ranges::Full
+        );
+        assert_eq!(
+            Ranges::parse("bytes=-3, 4-5, 20-", 10),
+            Ranges::Partial(vec![4..6, 7..10])
+        );
+    }
+
+    #[test]
+    fn unsatisfiable() {
+        assert_eq!(Ranges::parse("bytes=10-", 10), Ranges::Unsatisfiable);
+        assert_eq!(Ranges::parse("bytes=10-20, 30-", 10), Ranges::Unsatisfiable);
+        assert_eq!(Ranges::parse("bytes=-0", 10), Ranges::Unsatisfiable);
+        assert_eq!(Ranges::parse("bytes=-5", 0), Ranges::Unsatisfiable);
+        assert_eq!(Ranges::parse("bytes=0-", 0), Ranges::Unsatisfiable);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Ranges::parse("items=0-1", 10), Ranges::Full);
+        assert_eq!(Ranges::parse("bytes", 10), Ranges::Full);
+        assert_eq!(Ranges::parse("bytes=5-1", 10), Ranges::Full);
+        assert_eq!(Ranges::parse("bytes=a-1", 10), Ranges::Full);
+        assert_eq!(Ranges::parse("bytes=1", 10), Ranges::Full);
+        assert_eq!(Ranges::parse("bytes=-", 10), Ranges::Full);
+        assert_eq!(Ranges::parse("bytes=+1-2", 10), Ranges::Full);
+        assert_eq!(Ranges::parse("bytes=0-1, x", 10), Ranges::Full);
+    }
+
+    #[test]
+    fn multipart_body() {
+        assert_eq!(content_range(&(2..5), 10), "bytes 2-4/10");
+
+        let (boundary, body) = multipart(b"0123456789", &[0..2, 8..10], "text/plain");
+        assert_eq!(boundary, "embed_it_byteranges_00000000");
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            "\r\n--embed_it_byteranges_00000000\r\n\
+             Content-Type: text/plain\r\n\
+             Content-Range: bytes 0-1/10\r\n\r\n\
+             01\
+             \r\n--embed_it_byteranges_00000000\r\n\
+             Content-Type: text/plain\r\n\
+             Content-Range: bytes 8-9/10\r\n\r\n\
+             89\
+             \r\n--embed_it_byteranges_00000000--\r\n"
+        );
+    }
+
+    #[test]
+    fn unique_boundary() {
+        assert_eq!(
+            boundary(b"abc embed_it_byteranges_00000000 embed_it_byteranges_00000001"),
+            "embed_it_byteranges_00000002"
+        );
+    }
+}
@@ -0,0 +1,376 @@
+use std::{
+    convert::Infallible,
+    fmt::Write,
+    future::{Ready, ready},
+    marker::PhantomData,
+    ops::Deref,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use embed_it::{Entry, Index, LookupOptions, Redirect, Resolved, Resolver};
+use http::{
+    HeaderMap, Method, Request, Response, StatusCode, Uri,
+    header::{
+        ACCEPT_ENCODING, ACCEPT_RANGES, ALLOW, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_RANGE,
+        CONTENT_TYPE, ETAG, IF_NONE_MATCH, IF_RANGE, LAST_MODIFIED, LOCATION, RANGE, VARY,
+    },
+};
+use http_body_util::Full;
+
+use crate::{
+    Encoding, ServeFile,
+    encoding::AcceptEncoding,
+    range::{self, Ranges},
+};
+
+/// A [`tower_service::Service`] serving files of an embedded dir by the request path.
+///
+/// - the path is percent-decoded and resolved with a [`Resolver`]: a dir is served with its index file,
+///   an unknown path with the fallback file, and trailing slashes may be redirected with `308 Permanent Redirect`.
+///   A path with an encoded separator (`%2F`) or with invalid utf8 gets `404 Not Found` without the fallback.
+///   The path is decoded once, so the dir must not enable `index(percent_decode)`
+/// - only `GET` and `HEAD` are allowed, other methods get `405 Method Not Allowed`
+/// - unresolved paths get `404 Not Found`
+/// - the encoding is negotiated with `Accept-Encoding` over [`ServeFile::encoded_content`]
+/// - the `ETag` is made from [`ServeFile::hash`] and `If-None-Match` gets `304 Not Modified`
+/// - `Range` gets `206 Partial Content` with a single range or `multipart/byteranges`, and `416 Range Not Satisfiable`
+///   if no range overlaps the content. `If-Range` is validated against the `ETag` or [`ServeFile::modified`]
+pub struct ServeDir<T, D, F> {
+    dir: T,
+    resolver: Resolver<'static>,
+    _entry: PhantomData<fn() -> Entry<D, F>>,
+}
+
+impl<T, D, F> ServeDir<T, D, F>
+where
+    T: Index<D, F>,
+    D: Deref + 'static,
+    D::Target: Index<D, F>,
+    F: ServeFile + 'static,
+{
+    /// Create new instance of [`ServeDir`] with [`Resolver::DEFAULT`]
+    pub fn new(dir: T) -> Self {
+        Self {
+            dir,
+            resolver: Resolver::DEFAULT,
+            _entry: PhantomData,
+        }
+    }
+
+    /// Changes how request paths are resolved: index files of dirs, the fallback file and trailing slashes
+    pub fn resolver(mut self, resolver: Resolver<'static>) -> Self {
+        self.resolver = resolver;
+        self
+    }
+
+    /// Makes the response to a request with the method, uri and headers
+    pub fn respond(
+        &self,
+        method: &Method,
+        uri: &Uri,
+        headers: &HeaderMap,
+    ) -> Response<Full<Bytes>> {
+        if method != Method::GET && method != Method::HEAD {
+            return Response::builder()
+                .status(StatusCode::METHOD_NOT_ALLOWED)
+                .header(ALLOW, "GET, HEAD")
+                .body(Full::default())
+                .expect("the response is valid");
+        }
+
+        let Some(path) = DECODE.normalize(uri.path()) else {
+            return status(StatusCode::NOT_FOUND);
+        };
+        let file = match self.resolver.resolve(&self.dir, &path) {
+            Resolved::File(file) | Resolved::Fallback(file) => file,
+            Resolved::Redirect(redirect) => {
+                return Response::builder()
+                    .status(StatusCode::PERMANENT_REDIRECT)
+                    .header(LOCATION, location(uri, redirect))
+                    .body(Full::default())
+                    .expect("the response is valid");
+            }
+            Resolved::NotFound => return status(StatusCode::NOT_FOUND),
+        };
+
+        let (encoding, content) = AcceptEncoding::parse(headers.get_all(ACCEPT_ENCODING))
+            .negotiate(file.content(), |e| file.encoded_content(e));
+        let etag = file.hash().map(|hash| etag(hash, encoding));
+        let modified = file.modified().map(httpdate::fmt_http_date);
+
+        let mut response = Response::builder()
+            .header(VARY, ACCEPT_ENCODING.as_str())
+            .header(ACCEPT_RANGES, "bytes");
+        if let Some(etag) = &etag {
+            response = response.header(ETAG, etag.as_str());
+        }
+        if let Some(modified) = &modified {
+            response = response.header(LAST_MODIFIED, modified.as_str());
+        }
+
+        if etag
+            .as_deref()
+            .is_some_and(|etag| is_none_match(headers, etag))
+        {
+            return response
+                .status(StatusCode::NOT_MODIFIED)
+                .body(Full::default())
+                .expect("the response is valid");
+        }
+
+        if encoding != Encoding::Identity {
+            response = response.header(CONTENT_ENCODING, encoding.as_str());
+        }
+
+        let ranges = match headers.get(RANGE).and_then(|v| v.to_str().ok()) {
+            Some(range) if is_if_range_match(headers, etag.as_deref(), modified.as_deref()) => {
+                Ranges::parse(range, content.len())
+            }
+            _ => Ranges::Full,
+        };
+        let (status, content_type, body) = match ranges {
+            Ranges::Full => (
+                StatusCode::OK,
+                file.content_type().to_owned(),
+                Bytes::from_static(content),
+            ),
+            Ranges::Unsatisfiable => {
+                return response
+                    .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                    .header(CONTENT_RANGE, format!("bytes */{}", content.len()))
+                    .body(Full::default())
+                    .expect("the response is valid");
+            }
+            Ranges::Partial(ranges) => match ranges.as_slice() {
+                [range] => {
+                    response =
+                        response.header(CONTENT_RANGE, range::content_range(range, content.len()));
+                    (
+                        StatusCode::PARTIAL_CONTENT,
+                        file.content_type().to_owned(),
+                        Bytes::from_static(&content[range.clone()]),
+                    )
+                }
+                ranges => {
+                    let (boundary, body) = range::multipart(content, ranges, file.content_type());
+                    (
+                        StatusCode::PARTIAL_CONTENT,
+                        format!("multipart/byteranges; boundary={boundary}"),
+                        Bytes::from(body),
+                    )
+                }
+            },
+        };
+
+        let len = body.len();
+        let body = if method == Method::HEAD {
+            Full::default()
+        } else {
+            Full::new(body)
+        };
+        response
+            .status(status)
+            .header(CONTENT_TYPE, content_type)
+            .header(CONTENT_LENGTH, len)
+            .body(body)
+            .expect("the response is valid")
+    }
+}
+
+impl<T: Clone, D, F> Clone for ServeDir<T, D, F> {
+    fn clone(&self) -> Self {
+        Self {
+            dir: self.dir.clone(),
+            resolver: self.resolver,
+            _entry: PhantomData,
+        }
+    }
+}
+
+impl<T: Copy, D, F> Copy for ServeDir<T, D, F> {}
+
+impl<T: core::fmt::Debug, D, F> core::fmt::Debug for ServeDir<T, D, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ServeDir")
+            .field("dir", &self.dir)
+            .field("resolver", &self.resolver)
+            .finish()
+    }
+}
+
+impl<T, D, F, B> tower_service::Service<Request<B>> for ServeDir<T, D, F>
+where
+    T: Index<D, F>,
+    D: Deref + 'static,
+    D::Target: Index<D, F>,
+    F: ServeFile + 'static,
+{
+    type Response = Response<Full<Bytes>>;
+    type Error = Infallible;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        ready(Ok(self.respond(
+            request.method(),
+            request.uri(),
+            request.headers(),
+        )))
+    }
+}
+
+fn status(status: StatusCode) -> Response<Full<Bytes>> {
+    Response::builder()
+        .status(status)
+        .body(Full::default())
+        .expect("the response is valid")
+}
+
+/// A relative reference, so the redirect works when the service is nested under a prefix
+fn location(uri: &Uri, redirect: Redirect) -> String {
+    let path = uri.path();
+    let trimmed = path.trim_end_matches('/');
+    let name = trimmed.rsplit('/').next().unwrap_or_default();
+    let mut location = match redirect {
+        Redirect::AddSlash => format!("./{name}/"),
+        Redirect::RemoveSlash => format!("{}{name}", "../".repeat(path.len() - trimmed.len())),
+    };
+    if let Some(query) = uri.query() {
+        location.push('?');
+        location.push_str(query);
+    }
+    location
+}
+
+/// Request paths are percent-encoded
+const DECODE: LookupOptions = LookupOptions {
+    percent_decode: true,
+    ..LookupOptions::EXACT
+};
+
+/// A strong `ETag`, which is different for every encoding of the same content
+fn etag(hash: &[u8], encoding: Encoding) -> String {
+    let mut etag = String::with_capacity(hash.len() * 2 + 12);
+    etag.push('"');
+    for b in hash {
+        write!(&mut etag, "{b:02x}").expect("writing to a string never fails");
+    }
+    if encoding != Encoding::Identity {
+        etag.push('-');
+        etag.push_str(encoding.as_str());
+    }
+    etag.push('"');
+    etag
+}
+
+/// Does `If-None-Match` match the `ETag` with the weak comparison
+fn is_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(IF_NONE_MATCH)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(str::trim)
+        .any(|tag| tag == "*" || tag.strip_prefix("W/").unwrap_or(tag) == etag)
+}
+
+/// Should the `Range` be served: `If-Range` is absent or matches the strong `ETag` or the exact `Last-Modified`
+fn is_if_range_match(headers: &HeaderMap, etag: Option<&str>, modified: Option<&str>) -> bool {
+    let Some(if_range) = headers.get(IF_RANGE) else {
+        return true;
+    };
+    let Ok(if_range) = if_range.to_str().map(str::trim) else {
+        return false;
+    };
+
+    if if_range.starts_with('"') || if_range.starts_with("W/") {
+        etag.is_some_and(|etag| etag == if_range)
+    } else {
+        modified.is_some_and(|modified| {
+            httpdate::parse_http_date(if_range).ok() == httpdate::parse_http_date(modified).ok()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{
+        HeaderMap, HeaderValue,
+        header::{IF_NONE_MATCH, IF_RANGE},
+    };
+
+    use embed_it::Redirect;
+
+    use crate::Encoding;
+
+    use super::{etag, is_if_range_match, is_none_match, location};
+
+    #[test]
+    fn locations() {
+        let uri = |s: &'static str| s.parse().unwrap();
+        assert_eq!(location(&uri("/a/docs"), Redirect::AddSlash), "./docs/");
+        assert_eq!(
+            location(&uri("/docs?lang=en"), Redirect::AddSlash),
+            "./docs/?lang=en"
+        );
+        assert_eq!(
+            location(&uri("/a/app.js/"), Redirect::RemoveSlash),
+            "../app.js"
+        );
+        assert_eq!(
+            location(&uri("/app.js//?v=1"), Redirect::RemoveSlash),
+            "../../app.js?v=1"
+        );
+    }
+
+    #[test]
+    fn etags() {
+        assert_eq!(etag(&[0x0a, 0xff], Encoding::Identity), "\"0aff\"");
+        assert_eq!(etag(&[0x0a, 0xff], Encoding::Brotli), "\"0aff-br\"");
+    }
+
+    #[test]
+    fn none_match() {
+        let mut headers = HeaderMap::new();
+        assert!(!is_none_match(&headers, "\"a\""));
+
+        headers.append(IF_NONE_MATCH, HeaderValue::from_static("\"b\", W/\"a\""));
+        assert!(is_none_match(&headers, "\"a\""));
+        assert!(!is_none_match(&headers, "\"c\""));
+
+        headers.append(IF_NONE_MATCH, HeaderValue::from_static("*"));
+        assert!(is_none_match(&headers, "\"c\""));
+    }
+
+    #[test]
+    fn if_range() {
+        const MODIFIED: &str = "Sun, 06 Nov 1994 08:49:37 GMT";
+
+        let mut headers = HeaderMap::new();
+        assert!(is_if_range_match(&headers, None, None));
+
+        headers.insert(IF_RANGE, HeaderValue::from_static("\"a\""));
+        assert!(is_if_range_match(&headers, Some("\"a\""), None));
+        assert!(!is_if_range_match(&headers, Some("\"b\""), None));
+        assert!(!is_if_range_match(&headers, None, Some(MODIFIED)));
+
+        headers.insert(IF_RANGE, HeaderValue::from_static("W/\"a\""));
+        assert!(!is_if_range_match(&headers, Some("\"a\""), None));
+
+        headers.insert(IF_RANGE, HeaderValue::from_static(MODIFIED));
+        assert!(is_if_range_match(&headers, Some("\"a\""), Some(MODIFIED)));
+        assert!(!is_if_range_match(
+            &headers,
+            None,
+            Some("Sun, 06 Nov 1994 08:49:38 GMT")
+        ));
+        assert!(!is_if_range_match(&headers, Some("\"a\""), None));
+
+        headers.insert(IF_RANGE, HeaderValue::from_static("yesterday"));
+        assert!(!is_if_range_match(&headers, None, Some(MODIFIED)));
+    }
+}
@@ -0,0 +1,36 @@
+use std::time::SystemTime;
+
+use crate::Encoding;
+
+/// A file, which can be served by [`crate::ServeDir`].
+///
+/// The traits of a file are chosen with the `file(derive(..))` attribute, so the implementation
+/// maps them to HTTP: usually it is a few lines for the `DynFile` struct of the derive
+pub trait ServeFile {
+    /// The original content
+    fn content(&self) -> &'static [u8];
+
+    /// The value of the `Content-Type` header, e.g. from the `Mime` trait. Default: `application/octet-stream`
+    fn content_type(&self) -> &'static str {
+        "application/octet-stream"
+    }
+
+    /// The content compressed with the encoding, e.g. from the `Gzip`, `Brotli` or `Zstd` traits.
+    /// It is never called with [`Encoding::Identity`]. Default: `None`
+    fn encoded_content(&self, encoding: Encoding) -> Option<&'static [u8]> {
+        let _ = encoding;
+        None
+    }
+
+    /// A hash of the content, e.g. from a hash trait, which makes the `ETag` header.
+    /// Default: `None` and responses do not have the `ETag` header
+    fn hash(&self) -> Option<&'static [u8]> {
+        None
+    }
+
+    /// The modification time, e.g. from `Metadata::modified`, which makes the `Last-Modified` header
+    /// and validates `If-Range` dates. Default: `None`
+    fn modified(&self) -> Option<SystemTime> {
+        None
+    }
+}
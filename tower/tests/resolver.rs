@@ -0,0 +1,96 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+use bytes::Bytes;
+use embed_it::{Content, Resolver, TrailingSlash};
+use embed_it_tower::{ServeDir, ServeFile};
+use http::{
+    Request, Response, StatusCode,
+    header::{CONTENT_TYPE, LOCATION},
+};
+use http_body::Body;
+use http_body_util::Full;
+use pretty_assertions::assert_eq;
+use tower_service::Service;
+
+#[derive(embed_it::Embed)]
+#[embed(path = "$CARGO_MANIFEST_DIR/../example_dirs/spa", file(derive(Mime)))]
+pub struct App;
+
+impl ServeFile for DynFile {
+    fn content(&self) -> &'static [u8] {
+        Content::content(&**self)
+    }
+
+    fn content_type(&self) -> &'static str {
+        self.mime()
+    }
+}
+
+const SPA: Resolver<'static> = Resolver {
+    index_files: &["index.html"],
+    fallback: Some("index.html"),
+    trailing_slash: TrailingSlash::Redirect,
+};
+
+fn get(service: &mut ServeDir<App, DynDir, DynFile>, path: &str) -> Response<Full<Bytes>> {
+    service
+        .call(Request::get(path).body(()).unwrap())
+        .into_inner()
+        .unwrap()
+}
+
+fn body(response: Response<Full<Bytes>>) -> Vec<u8> {
+    let mut body = response.into_body();
+    let mut cx = Context::from_waker(Waker::noop());
+    match Pin::new(&mut body).poll_frame(&mut cx) {
+        Poll::Ready(Some(frame)) => frame.unwrap().into_data().unwrap().to_vec(),
+        Poll::Ready(None) => Vec::new(),
+        Poll::Pending => unreachable!("the body is always ready"),
+    }
+}
+
+#[test]
+fn index_files() {
+    let mut service = ServeDir::new(App);
+    let response = get(&mut service, "/");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[CONTENT_TYPE], "text/html; charset=utf-8");
+    assert_eq!(body(response), App.index().content());
+
+    let response = get(&mut service, "/docs");
+    assert_eq!(body(response), App.docs().index().content());
+
+    assert_eq!(
+        get(&mut service, "/some/route").status(),
+        StatusCode::NOT_FOUND
+    );
+}
+
+#[test]
+fn spa() {
+    let mut service = ServeDir::new(App).resolver(SPA);
+    let response = get(&mut service, "/some/route?id=1");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body(response), App.index().content());
+
+    let response = get(&mut service, "/docs/");
+    assert_eq!(body(response), App.docs().index().content());
+
+    let response = get(&mut service, "/app.js");
+    assert_eq!(body(response), App.app().content());
+}
+
+#[test]
+fn redirects() {
+    let mut service = ServeDir::new(App).resolver(SPA);
+    let response = get(&mut service, "/docs?lang=en");
+    assert_eq!(response.status(), StatusCode::PERMANENT_REDIRECT);
+    assert_eq!(response.headers()[LOCATION], "./docs/?lang=en");
+
+    let response = get(&mut service, "/app.js/");
+    assert_eq!(response.status(), StatusCode::PERMANENT_REDIRECT);
+    assert_eq!(response.headers()[LOCATION], "../app.js");
+}
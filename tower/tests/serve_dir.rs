@@ -0,0 +1,297 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll, Waker},
+    time::SystemTime,
+};
+
+use bytes::Bytes;
+use embed_it::Content;
+use embed_it_tower::{Encoding, ServeDir, ServeFile};
+use http::{
+    Method, Request, Response, StatusCode,
+    header::{
+        ACCEPT_ENCODING, ACCEPT_RANGES, ALLOW, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_RANGE,
+        CONTENT_TYPE, ETAG, IF_NONE_MATCH, LAST_MODIFIED, VARY,
+    },
+};
+use http_body::Body;
+use http_body_util::Full;
+use pretty_assertions::assert_eq;
+use tower_service::Service;
+
+#[derive(embed_it::Embed)]
+#[embed(
+    path = "$CARGO_MANIFEST_DIR/../example_dirs/compressible",
+    file(derive(Sha2_256), derive(Gzip), derive(Brotli), derive(Mime))
+)]
+pub struct Assets;
+
+impl ServeFile for DynFile {
+    fn content(&self) -> &'static [u8] {
+        Content::content(&**self)
+    }
+
+    fn content_type(&self) -> &'static str {
+        self.mime()
+    }
+
+    fn encoded_content(&self, encoding: Encoding) -> Option<&'static [u8]> {
+        match encoding {
+            Encoding::Gzip => Some(self.gzip_content()),
+            Encoding::Brotli => Some(self.brotli_content()),
+            _ => None,
+        }
+    }
+
+    fn hash(&self) -> Option<&'static [u8]> {
+        Some(self.sha2_256())
+    }
+
+    fn modified(&self) -> Option<SystemTime> {
+        self.metadata().modified()
+    }
+}
+
+fn request(method: Method, path: &str, headers: &[(&str, &str)]) -> Response<Full<Bytes>> {
+    let mut request = Request::builder().method(method).uri(path);
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    ServeDir::new(Assets)
+        .call(request.body(()).unwrap())
+        .into_inner()
+        .unwrap()
+}
+
+fn get(path: &str, headers: &[(&str, &str)]) -> Response<Full<Bytes>> {
+    request(Method::GET, path, headers)
+}
+
+fn body(response: Response<Full<Bytes>>) -> Vec<u8> {
+    let mut body = response.into_body();
+    let mut cx = Context::from_waker(Waker::noop());
+    match Pin::new(&mut body).poll_frame(&mut cx) {
+        Poll::Ready(Some(frame)) => frame.unwrap().into_data().unwrap().to_vec(),
+        Poll::Ready(None) => Vec::new(),
+        Poll::Pending => unreachable!("the body is always ready"),
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[test]
+fn identity() {
+    let response = get("/lorem.txt", &[]);
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[CONTENT_TYPE],
+        "text/plain; charset=utf-8"
+    );
+    assert_eq!(response.headers()[VARY], "accept-encoding");
+    assert_eq!(
+        response.headers()[ETAG],
+        format!("\"{}\"", hex(Assets.lorem().sha2_256())).as_str()
+    );
+    assert_eq!(
+        response.headers()[CONTENT_LENGTH],
+        Assets.lorem().content().len().to_string().as_str()
+    );
+    assert!(!response.headers().contains_key(CONTENT_ENCODING));
+    assert_eq!(body(response), Assets.lorem().content());
+}
+
+#[test]
+fn negotiation() {
+    let response = get("/lorem.txt", &[("accept-encoding", "gzip;q=0.5, br")]);
+    assert_eq!(response.headers()[CONTENT_ENCODING], "br");
+    assert_eq!(
+        response.headers()[ETAG],
+        format!("\"{}-br\"", hex(Assets.lorem().sha2_256())).as_str()
+    );
+    assert_eq!(body(response), Assets.lorem().brotli_content());
+
+    let response = get("/lorem.txt", &[("accept-encoding", "gzip, br;q=0.5")]);
+    assert_eq!(response.headers()[CONTENT_ENCODING], "gzip");
+    assert_eq!(body(response), Assets.lorem().gzip_content());
+
+    let response = get("/lorem.txt", &[("accept-encoding", "gzip, br")]);
+    assert_eq!(response.headers()[CONTENT_ENCODING], "br");
+
+    let response = get("/lorem.txt", &[("accept-encoding", "zstd")]);
+    assert!(!response.headers().contains_key(CONTENT_ENCODING));
+    assert_eq!(body(response), Assets.lorem().content());
+}
+
+#[test]
+fn not_modified() {
+    let etag = get("/tiny.txt", &[]).headers()[ETAG].clone();
+    let response = get("/tiny.txt", &[("if-none-match", etag.to_str().unwrap())]);
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers()[ETAG], etag);
+    assert_eq!(body(response), b"");
+
+    // the gzip representation has another etag
+    let response = get(
+        "/tiny.txt",
+        &[
+            (IF_NONE_MATCH.as_str(), etag.to_str().unwrap()),
+            (ACCEPT_ENCODING.as_str(), "gzip"),
+        ],
+    );
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = get("/tiny.txt", &[("if-none-match", "\"other\"")]);
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[test]
+fn not_found() {
+    assert_eq!(get("/missing.txt", &[]).status(), StatusCode::NOT_FOUND);
+    assert_eq!(get("/", &[]).status(), StatusCode::NOT_FOUND);
+}
+
+#[test]
+fn percent_encoded_path() {
+    let response = get("/tiny%2Etxt", &[]);
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body(response), Assets.tiny().content());
+
+    assert_eq!(get("/%74iny.txt", &[]).status(), StatusCode::OK);
+    assert_eq!(
+        get("/a%2F..%2Ftiny.txt", &[]).status(),
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(get("/%ff", &[]).status(), StatusCode::NOT_FOUND);
+}
+
+#[test]
+fn methods() {
+    let response = request(Method::HEAD, "/tiny.txt", &[]);
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[CONTENT_LENGTH],
+        Assets.tiny().content().len().to_string().as_str()
+    );
+    assert_eq!(body(response), b"");
+
+    let response = request(Method::POST, "/tiny.txt", &[]);
+    assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(response.headers()[ALLOW], "GET, HEAD");
+}
+
+#[test]
+fn single_range() {
+    let content = Assets.lorem().content();
+    let response = get("/lorem.txt", &[("range", "bytes=6-10")]);
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(response.headers()[ACCEPT_RANGES], "bytes");
+    assert_eq!(
+        response.headers()[CONTENT_TYPE],
+        "text/plain; charset=utf-8"
+    );
+    assert_eq!(
+        response.headers()[CONTENT_RANGE],
+        format!("bytes 6-10/{}", content.len()).as_str()
+    );
+    assert_eq!(response.headers()[CONTENT_LENGTH], "5");
+    assert_eq!(body(response), &content[6..11]);
+
+    let response = get("/lorem.txt", &[("range", "bytes=-4")]);
+    assert_eq!(body(response), &content[content.len() - 4..]);
+}
+
+#[test]
+fn encoded_range() {
+    let gzip = Assets.lorem().gzip_content();
+    let response = get(
+        "/lorem.txt",
+        &[("range", "bytes=0-1"), ("accept-encoding", "gzip")],
+    );
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(response.headers()[CONTENT_ENCODING], "gzip");
+    assert_eq!(
+        response.headers()[CONTENT_RANGE],
+        format!("bytes 0-1/{}", gzip.len()).as_str()
+    );
+    assert_eq!(body(response), &gzip[..2]);
+}
+
+#[test]
+fn multiple_ranges() {
+    let content = Assets.lorem().content();
+    let response = get("/lorem.txt", &[("range", "bytes=0-4, 6-10")]);
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+    assert!(!response.headers().contains_key(CONTENT_RANGE));
+
+    let content_type = response.headers()[CONTENT_TYPE].to_str().unwrap();
+    let boundary = content_type
+        .strip_prefix("multipart/byteranges; boundary=")
+        .unwrap()
+        .to_owned();
+    let expected = format!(
+        "\r\n--{boundary}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Range: bytes 0-4/{len}\r\n\r\n{first}\
+         \r\n--{boundary}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Range: bytes 6-10/{len}\r\n\r\n{second}\
+         \r\n--{boundary}--\r\n",
+        len = content.len(),
+        first = std::str::from_utf8(&content[0..5]).unwrap(),
+        second = std::str::from_utf8(&content[6..11]).unwrap(),
+    );
+    assert_eq!(
+        response.headers()[CONTENT_LENGTH],
+        expected.len().to_string().as_str()
+    );
+    assert_eq!(String::from_utf8(body(response)).unwrap(), expected);
+}
+
+#[test]
+fn unsatisfiable_range() {
+    let len = Assets.tiny().content().len();
+    let response = get("/tiny.txt", &[("range", "bytes=100-")]);
+    assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+    assert_eq!(
+        response.headers()[CONTENT_RANGE],
+        format!("bytes */{len}").as_str()
+    );
+    assert_eq!(body(response), b"");
+
+    // an invalid header is ignored
+    let response = get("/tiny.txt", &[("range", "bytes=5-1")]);
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[test]
+fn if_range() {
+    let response = get("/lorem.txt", &[]);
+    let etag = response.headers()[ETAG].to_str().unwrap().to_owned();
+    let modified = response.headers()[LAST_MODIFIED]
+        .to_str()
+        .unwrap()
+        .to_owned();
+
+    let response = get("/lorem.txt", &[("range", "bytes=0-1"), ("if-range", &etag)]);
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+
+    let response = get(
+        "/lorem.txt",
+        &[("range", "bytes=0-1"), ("if-range", &modified)],
+    );
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+
+    let response = get(
+        "/lorem.txt",
+        &[("range", "bytes=0-1"), ("if-range", "\"outdated\"")],
+    );
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body(response), Assets.lorem().content());
+
+    let response = get(
+        "/lorem.txt",
+        &[
+            ("range", "bytes=0-1"),
+            ("if-range", "Sun, 06 Nov 1994 08:49:37 GMT"),
+        ],
+    );
+    assert_eq!(response.status(), StatusCode::OK);
+}
@@ -1,6 +1,7 @@
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Entry<Dir, File = Dir> {
     Dir(Dir),
     File(File),
@@ -105,7 +106,7 @@ impl Ord for EntryKind {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::cmp::Ordering::*;
+    use core::cmp::Ordering::*;
 
     fn dir<T>(v: T) -> Entry<T> {
         Entry::Dir(v)
@@ -0,0 +1,143 @@
+use std::{
+    env::VarError,
+    fmt,
+    string::{String, ToString},
+};
+
+/// Replaces environment variables (`$VAR` or `${VAR}`) and separators with the ones of the platform,
+/// but does not require the path to exist.
+///
+/// It is shared by the derive and the build script helpers, so a path resolves the same way in both.
+/// A `$`, which is not followed by a name, stays as is
+pub fn expand(
+    input: &str,
+    get_env: impl Fn(&str) -> Result<String, VarError>,
+) -> Result<String, ExpandError> {
+    let mut result = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find('$') {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let name_len = after
+            .find(|c: char| !is_name_char(c))
+            .unwrap_or(after.len());
+        let (var, tail) = if name_len > 0 {
+            after.split_at(name_len)
+        } else {
+            match after
+                .strip_prefix('{')
+                .and_then(|braced| braced.split_once('}'))
+            {
+                Some((var, tail)) if !var.is_empty() => (var, tail),
+                _ => ("", after),
+            }
+        };
+
+        if var.is_empty() {
+            result.push('$');
+        } else {
+            let value = get_env(var).map_err(|error| ExpandError {
+                var: var.to_string(),
+                error,
+            })?;
+            result.push_str(&value);
+        }
+        rest = tail;
+    }
+    result.push_str(rest);
+
+    let result = if std::path::MAIN_SEPARATOR == '\\' {
+        result.replace('/', std::path::MAIN_SEPARATOR_STR)
+    } else {
+        result.replace('\\', std::path::MAIN_SEPARATOR_STR)
+    };
+    Ok(result)
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// An environment variable of a path can't be read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpandError {
+    pub var: String,
+    pub error: VarError,
+}
+
+impl fmt::Display for ExpandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "environment variable '{}' error: '{}'",
+            self.var, self.error
+        )
+    }
+}
+
+impl std::error::Error for ExpandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        borrow::ToOwned,
+        env::VarError,
+        string::{String, ToString},
+    };
+
+    use super::{ExpandError, expand};
+
+    fn get_env(var: &str) -> Result<String, VarError> {
+        match var {
+            "ROOT" => Ok("/root".to_owned()),
+            "DIR" => Ok("dir".to_owned()),
+            "WITH SPACE" => Ok("space".to_owned()),
+            _ => Err(VarError::NotPresent),
+        }
+    }
+
+    fn check(input: &str, expected: &str) {
+        let expected = expected.replace('/', std::path::MAIN_SEPARATOR_STR);
+        assert_eq!(expand(input, get_env).unwrap(), expected, "{input}");
+    }
+
+    #[test]
+    fn vars() {
+        check("$ROOT/assets", "/root/assets");
+        check("${ROOT}/assets", "/root/assets");
+        check("$ROOT/${DIR}/$DIR", "/root/dir/dir");
+        check("${ROOT}${DIR}", "/rootdir");
+        check("$DIR.json", "dir.json");
+        check("${WITH SPACE}", "space");
+    }
+
+    #[test]
+    fn literals() {
+        check("/a/b", "/a/b");
+        check("a\\b", "a/b");
+        check("/a/$/b", "/a/$/b");
+        check("/a/${}/b", "/a/${}/b");
+        check("/a/${ROOT", "/a/${ROOT");
+        check("$", "$");
+    }
+
+    #[test]
+    fn unknown_var() {
+        let err = expand("$UNKNOWN/assets", get_env).unwrap_err();
+        assert_eq!(
+            err,
+            ExpandError {
+                var: "UNKNOWN".to_owned(),
+                error: VarError::NotPresent
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "environment variable 'UNKNOWN' error: 'environment variable not found'"
+        );
+    }
+}
@@ -1 +1,8 @@
+#![no_std]
+
+#[cfg(feature = "expand")]
+extern crate std;
+
 pub mod entry;
+#[cfg(feature = "expand")]
+pub mod expand;